@@ -0,0 +1,293 @@
+//! Compile-time boolean function minimization based on [Quine-McCluskey algorithm](https://en.wikipedia.org/wiki/Quine%E2%80%93McCluskey_algorithm).
+//!
+//! The companion of the [`quine-mccluskey`](https://docs.rs/quine-mccluskey) crate.
+//! See [`boolean_fn!`] for details.
+
+use std::collections::HashSet;
+
+use proc_macro2::{Delimiter, Span, TokenTree};
+use quine_mccluskey as qmc;
+use quote::quote;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{
+    braced, bracketed, parenthesized, parse_macro_input, Attribute, Ident, LitInt, Token,
+    Visibility,
+};
+
+/// Minimizes a boolean function at compile time and expands to a function returning its minimized expression.
+///
+/// The function takes a `bool` parameter for each of the variables, the first one being the most significant bit
+/// of the terms. Parameter names are sanitized the same way as by [`RustCode`], e.g. `r#type` becomes `r_type`.
+/// Its body lists the terms of the boolean function:
+///
+/// * `minterms` and `dont_cares` are minimized with [`minimize_minterms`] in SOP form.
+/// * `maxterms` and `dont_cares` are minimized with [`minimize_maxterms`] in POS form.
+/// * `minterms` and `maxterms` are minimized with [`minimize`] in SOP form,
+///   the rest of the terms being don't care conditions.
+///
+/// The function is minimized again whenever the crate using it is compiled,
+/// so changing the terms is enough to update the expression.
+///
+/// Bad input is reported as a compile error pointing at the offending variable or term.
+///
+/// [`minimize`]: https://docs.rs/quine-mccluskey/latest/quine_mccluskey/fn.minimize.html
+/// [`minimize_minterms`]: https://docs.rs/quine-mccluskey/latest/quine_mccluskey/fn.minimize_minterms.html
+/// [`minimize_maxterms`]: https://docs.rs/quine-mccluskey/latest/quine_mccluskey/fn.minimize_maxterms.html
+/// [`RustCode`]: https://docs.rs/quine-mccluskey/latest/quine_mccluskey/struct.RustCode.html
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey_macros::boolean_fn;
+///
+/// boolean_fn! {
+///     /// Expands to `(a && c) || (!a && !c)`.
+///     pub fn f(a, b, c) {
+///         minterms: [0, 5],
+///         dont_cares: [2, 7],
+///     }
+/// }
+///
+/// assert!(f(false, false, false));
+/// assert!(!f(false, false, true));
+/// assert!(f(true, false, true));
+/// ```
+///
+/// Conflicting terms are rejected:
+///
+/// ```compile_fail
+/// use quine_mccluskey_macros::boolean_fn;
+///
+/// boolean_fn! {
+///     fn f(a, b, c) {
+///         minterms: [0, 5],
+///         dont_cares: [2, 5],
+///     }
+/// }
+/// ```
+#[proc_macro]
+pub fn boolean_fn(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let function = parse_macro_input!(input as BooleanFn);
+
+    match function.expand() {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+struct BooleanFn {
+    attributes: Vec<Attribute>,
+    visibility: Visibility,
+    name: Ident,
+    variables: Punctuated<Ident, Token![,]>,
+    parameters_span: Span,
+    minterms: Option<Vec<LitInt>>,
+    maxterms: Option<Vec<LitInt>>,
+    dont_cares: Option<Vec<LitInt>>,
+}
+
+impl Parse for BooleanFn {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let attributes = input.call(Attribute::parse_outer)?;
+        let visibility = input.parse()?;
+        input.parse::<Token![fn]>()?;
+        let name = input.parse()?;
+
+        let parameters;
+        let parentheses = parenthesized!(parameters in input);
+        let variables = parameters.parse_terminated(Ident::parse, Token![,])?;
+
+        let body;
+        let braces = braced!(body in input);
+        let mut function = BooleanFn {
+            attributes,
+            visibility,
+            name,
+            variables,
+            parameters_span: parentheses.span.join(),
+            minterms: None,
+            maxterms: None,
+            dont_cares: None,
+        };
+
+        while !body.is_empty() {
+            let key = body.parse::<Ident>()?;
+            body.parse::<Token![:]>()?;
+
+            let list;
+            bracketed!(list in body);
+            let terms = list
+                .parse_terminated(LitInt::parse, Token![,])?
+                .into_iter()
+                .collect();
+
+            let field = match key.to_string().as_str() {
+                "minterms" => &mut function.minterms,
+                "maxterms" => &mut function.maxterms,
+                "dont_cares" => &mut function.dont_cares,
+                _ => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        "expected `minterms`, `maxterms` or `dont_cares`",
+                    ))
+                }
+            };
+
+            if field.replace(terms).is_some() {
+                return Err(syn::Error::new(key.span(), format!("duplicate `{}`", key)));
+            }
+
+            if body.is_empty() {
+                break;
+            }
+
+            body.parse::<Token![,]>()?;
+        }
+
+        match (&function.minterms, &function.maxterms, &function.dont_cares) {
+            (Some(_), None, _) | (None, Some(_), _) | (Some(_), Some(_), None) => Ok(function),
+            (None, None, _) => Err(syn::Error::new(
+                braces.span.join(),
+                "expected `minterms` or `maxterms`",
+            )),
+            (Some(_), Some(_), Some(_)) => Err(syn::Error::new(
+                braces.span.join(),
+                "`dont_cares` can't be given with both `minterms` and `maxterms`, \
+                 the unlisted terms are already don't cares",
+            )),
+        }
+    }
+}
+
+impl BooleanFn {
+    fn expand(&self) -> syn::Result<proc_macro2::TokenStream> {
+        let variables = self
+            .variables
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+        let minterms = parse_terms(&self.minterms)?;
+        let maxterms = parse_terms(&self.maxterms)?;
+        let dont_cares = parse_terms(&self.dont_cares)?;
+
+        // The second term set of the minimization, whose literals are blamed for conflicts.
+        let (result, second_terms) = match (&self.minterms, &self.maxterms) {
+            (Some(_), None) => (
+                qmc::minimize_minterms(&variables, &minterms, &dont_cares, false, None),
+                &self.dont_cares,
+            ),
+            (None, Some(_)) => (
+                qmc::minimize_maxterms(&variables, &maxterms, &dont_cares, false, None),
+                &self.dont_cares,
+            ),
+            _ => (
+                qmc::minimize(&variables, &minterms, &maxterms, qmc::SOP, false, None),
+                &self.maxterms,
+            ),
+        };
+
+        let solution = result
+            .map_err(|error| self.error(error, second_terms))?
+            .pop()
+            .unwrap();
+
+        // The whole function is generated so that the parameters and the expression share the identifiers
+        // sanitized by `RustCode`, e.g. `r_type` for `r#type`, with unused parameters prefixed by `_`.
+        let function = qmc::RustCode::default()
+            .function(&self.name.to_string(), &variables, &solution)
+            .map_err(|error| self.error(error, second_terms))?
+            .parse::<proc_macro2::TokenStream>()?;
+        let (lints, parameters, expression) = function_parts(function);
+
+        let attributes = &self.attributes;
+        let visibility = &self.visibility;
+        let name = &self.name;
+
+        Ok(quote! {
+            #(#attributes)*
+            #(#[#lints])*
+            #visibility fn #name(#parameters) -> bool {
+                #expression
+            }
+        })
+    }
+
+    /// Maps the [`Error`](qmc::Error) to a compile error pointing at the offending variables or terms.
+    fn error(&self, error: qmc::Error, second_terms: &Option<Vec<LitInt>>) -> syn::Error {
+        let message = error.to_string();
+        let spans = match &error {
+            qmc::Error::DuplicateVariables(_) => {
+                let mut seen = HashSet::new();
+
+                self.variables
+                    .iter()
+                    .filter(|variable| !seen.insert(variable.to_string()))
+                    .map(Ident::span)
+                    .collect()
+            }
+            qmc::Error::TermOutOfBounds {
+                offending_terms, ..
+            } => [&self.minterms, &self.maxterms, &self.dont_cares]
+                .into_iter()
+                .flatten()
+                .flatten()
+                .filter(|term| contains(offending_terms, term))
+                .map(LitInt::span)
+                .collect(),
+            qmc::Error::TermConflict(conflicting_terms) => second_terms
+                .iter()
+                .flatten()
+                .filter(|term| contains(conflicting_terms, term))
+                .map(LitInt::span)
+                .collect(),
+            qmc::Error::InvalidVariableCount(_) => vec![self.parameters_span],
+            _ => vec![],
+        };
+
+        spans
+            .into_iter()
+            .map(|span| syn::Error::new(span, &message))
+            .reduce(|mut combined, error| {
+                combined.combine(error);
+                combined
+            })
+            .unwrap_or_else(|| syn::Error::new(Span::call_site(), message))
+    }
+}
+
+/// Splits a function generated by [`qmc::RustCode::function`] into
+/// the contents of its attributes, its parameter list and its body.
+fn function_parts(
+    function: proc_macro2::TokenStream,
+) -> (
+    Vec<proc_macro2::TokenStream>,
+    proc_macro2::TokenStream,
+    proc_macro2::TokenStream,
+) {
+    let mut attributes = vec![];
+    let mut parameters = None;
+    let mut body = None;
+
+    for tree in function {
+        if let TokenTree::Group(group) = tree {
+            match group.delimiter() {
+                Delimiter::Bracket => attributes.push(group.stream()),
+                Delimiter::Parenthesis if parameters.is_none() => parameters = Some(group.stream()),
+                Delimiter::Brace => body = Some(group.stream()),
+                _ => {}
+            }
+        }
+    }
+
+    (attributes, parameters.unwrap(), body.unwrap())
+}
+
+fn parse_terms(terms: &Option<Vec<LitInt>>) -> syn::Result<Vec<u32>> {
+    terms.iter().flatten().map(LitInt::base10_parse).collect()
+}
+
+fn contains(terms: &HashSet<u32>, term: &LitInt) -> bool {
+    term.base10_parse()
+        .map_or(false, |term: u32| terms.contains(&term))
+}
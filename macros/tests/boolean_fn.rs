@@ -0,0 +1,93 @@
+use quine_mccluskey_macros::boolean_fn;
+
+boolean_fn! {
+    fn minterms(a, b, c) {
+        minterms: [0, 5],
+        dont_cares: [2, 7],
+    }
+}
+
+boolean_fn! {
+    pub(crate) fn maxterms(a, b, c) {
+        maxterms: [1, 3, 4, 6],
+        dont_cares: [2, 7]
+    }
+}
+
+boolean_fn! {
+    /// A 2-to-1 multiplexer.
+    #[inline]
+    fn mux(sel, a, b) {
+        minterms: [2, 3, 5, 7],
+        maxterms: [0, 1, 4, 6],
+    }
+}
+
+boolean_fn! {
+    fn unused(a, b) {
+        minterms: [2, 3],
+        dont_cares: [],
+    }
+}
+
+boolean_fn! {
+    fn one(a) {
+        minterms: [0, 1],
+    }
+}
+
+boolean_fn! {
+    fn zero(a, b) {
+        maxterms: [0, 1, 2, 3],
+    }
+}
+
+boolean_fn! {
+    fn keywords(gen, r#type, gen_, Sel) {
+        minterms: [1, 2, 4, 8],
+        maxterms: [0, 3, 5, 6, 9, 10, 12],
+    }
+}
+
+#[test]
+fn terms() {
+    for term in 0..8 {
+        let inputs = [term & 4 != 0, term & 2 != 0, term & 1 != 0];
+        let [x, y, z] = inputs;
+
+        if [0, 5].contains(&term) {
+            assert!(minterms(x, y, z));
+            assert!(maxterms(x, y, z));
+        } else if [1, 3, 4, 6].contains(&term) {
+            assert!(!minterms(x, y, z));
+            assert!(!maxterms(x, y, z));
+        }
+
+        assert_eq!(mux(x, y, z), if x { z } else { y });
+    }
+}
+
+#[test]
+fn constants() {
+    for term in 0..4 {
+        let (a, b) = (term & 2 != 0, term & 1 != 0);
+
+        assert_eq!(unused(a, b), a);
+        assert!(one(a));
+        assert!(!zero(a, b));
+    }
+}
+
+#[test]
+fn identifiers() {
+    for term in 0..16 {
+        let inputs = [term & 8 != 0, term & 4 != 0, term & 2 != 0, term & 1 != 0];
+        let [a, b, c, d] = inputs;
+
+        if [1, 2, 4, 8].contains(&term) {
+            assert!(keywords(a, b, c, d));
+        } else if [0, 3, 5, 6, 9, 10, 12].contains(&term) {
+            assert!(!keywords(a, b, c, d));
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::implicant::Implicant;
+use crate::prime_implicant_chart::PrimeImplicantChart;
+use crate::Solution;
+
+/// The result of [`Minimizer::solve_anytime`](crate::Minimizer::solve_anytime).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AnytimeSolutions {
+    /// The minimal solutions, or a single valid but possibly larger one if solving timed out.
+    pub solutions: Vec<Solution>,
+    /// Whether the solutions are known to be minimal.
+    pub is_optimal: bool,
+    /// An upper bound of how many more terms the solution has than a minimal one, 0 if it is optimal
+    /// and `None` if solving timed out before the prime implicants were found.
+    pub optimality_gap: Option<usize>,
+}
+
+/// Covers the chart with its essential prime implicants, followed by the implicant covering
+/// the most remaining terms until all are covered, returning the cover and a lower bound of its size.
+///
+/// The lower bound adds the number of remaining terms no two of which share an implicant,
+/// as each of them needs its own, to the number of essential prime implicants.
+pub(crate) fn greedy_cover(chart: &PrimeImplicantChart) -> (Vec<Implicant>, usize) {
+    let implicants = chart.implicants();
+    let term_count = chart.terms().len();
+    let rows_of = |x: usize| {
+        (0..implicants.len())
+            .filter(|&y| chart.is_marked(y, x))
+            .collect::<Vec<_>>()
+    };
+
+    let mut cover = HashSet::new();
+
+    for x in 0..term_count {
+        if let [y] = rows_of(x)[..] {
+            cover.insert(y);
+        }
+    }
+
+    let is_covered =
+        |cover: &HashSet<usize>, x: usize| cover.iter().any(|&y| chart.is_marked(y, x));
+
+    let mut remaining_terms = (0..term_count)
+        .filter(|&x| !is_covered(&cover, x))
+        .collect::<Vec<_>>();
+    remaining_terms.sort_by_key(|&x| (rows_of(x).len(), chart.terms()[x]));
+
+    let mut used_rows = HashSet::new();
+    let mut independent_term_count = 0;
+
+    for &x in &remaining_terms {
+        let rows = rows_of(x);
+
+        if rows.iter().all(|y| !used_rows.contains(y)) {
+            used_rows.extend(rows);
+            independent_term_count += 1;
+        }
+    }
+
+    let lower_bound = cover.len() + independent_term_count;
+
+    while !remaining_terms.is_empty() {
+        let best = (0..implicants.len())
+            .filter(|y| !cover.contains(y))
+            .max_by_key(|&y| {
+                let covered_count = remaining_terms
+                    .iter()
+                    .filter(|&&x| chart.is_marked(y, x))
+                    .count();
+
+                // Prefer fewer literals, then the smaller implicant to be deterministic.
+                (
+                    covered_count,
+                    implicants[y].wildcard_count(),
+                    std::cmp::Reverse(implicants[y]),
+                )
+            })
+            .unwrap();
+
+        cover.insert(best);
+        remaining_terms.retain(|&x| !chart.is_marked(best, x));
+    }
+
+    let cover = cover.into_iter().map(|y| implicants[y]).collect();
+
+    (cover, lower_bound)
+}
+
+/// Covers the terms by growing each uncovered one into a cube free of the other terms,
+/// one variable at a time, without knowing the prime implicants.
+pub(crate) fn expansion_cover(
+    variable_count: u32,
+    terms: &HashSet<u32>,
+    other_terms: &HashSet<u32>,
+) -> Vec<Implicant> {
+    let mut terms = terms.iter().copied().collect::<Vec<_>>();
+    terms.sort_unstable();
+
+    let mut cover: Vec<Implicant> = vec![];
+
+    for term in terms {
+        if cover.iter().any(|implicant| implicant.contains(term)) {
+            continue;
+        }
+
+        let mut cube = Implicant::new(term);
+
+        for i in 0..variable_count {
+            let bit = 1 << i;
+            let mirror = Implicant::from_parts(cube.value() ^ bit, cube.mask());
+
+            if mirror
+                .get_terms()
+                .iter()
+                .all(|term| !other_terms.contains(term))
+            {
+                cube = Implicant::from_parts(cube.value(), cube.mask() | bit);
+            }
+        }
+
+        cover.push(cube);
+    }
+
+    cover
+}
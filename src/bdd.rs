@@ -0,0 +1,528 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::netlist::quote;
+use crate::{own_variables, validate_input, Error, Form, Solution};
+
+const ZERO: usize = 0;
+const ONE: usize = 1;
+
+/// A reduced ordered binary decision diagram (ROBDD) of a boolean function.
+///
+/// Every function has exactly one ROBDD for a given variable order, so two diagrams with the same variables
+/// in the same order are equal if and only if they represent the same function. The size of the diagram
+/// depends heavily on the order, see [`Bdd::reorder`].
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["a1", "a2", "b1", "b2"];
+/// // (a1 ∧ b1) ∨ (a2 ∧ b2)
+/// let minterms = [5, 7, 10, 11, 13, 14, 15];
+///
+/// let bdd = qmc::Bdd::from_terms(&variables, &minterms, &[]).unwrap();
+/// let interleaved = bdd.reorder(&["a1", "b1", "a2", "b2"]).unwrap();
+///
+/// assert_eq!((bdd.node_count(), interleaved.node_count()), (6, 4));
+/// assert!((0..16).all(|term| interleaved.evaluate(term) == minterms.contains(&term)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Bdd {
+    variables: Vec<String>,
+    /// The indices of the variables from the root level down.
+    order: Vec<usize>,
+    /// The terminals followed by the decision nodes, each after its children.
+    nodes: Vec<Node>,
+    root: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct Node {
+    level: usize,
+    low: usize,
+    high: usize,
+}
+
+/// A binary operation for [`Bdd::apply`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Operation {
+    And,
+    Or,
+    Xor,
+}
+
+impl Bdd {
+    /// Builds the diagram of the boolean function represented by the given `minterms` and `dont_cares`,
+    /// with the variables ordered as given.
+    ///
+    /// A don't care is chosen to be 1 or 0 where that removes a node, e.g. a variable whose one cofactor
+    /// consists only of don't cares doesn't get a node.
+    ///
+    /// Returns an [`Error`] for the same bad input as [`minimize_minterms`](crate::minimize_minterms).
+    pub fn from_terms<T: AsRef<str>>(
+        variables: &[T],
+        minterms: &[u32],
+        dont_cares: &[u32],
+    ) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+        let minterm_set = minterms.iter().copied().collect::<HashSet<_>>();
+        let dont_care_set = dont_cares.iter().copied().collect::<HashSet<_>>();
+
+        validate_input(&variables, &minterm_set, &dont_care_set)?;
+
+        let order = (0..variables.len()).collect();
+        let mut manager = Manager::new(variables.len());
+        let root = manager.build(
+            0,
+            minterm_set.into_iter().collect(),
+            dont_care_set.into_iter().collect(),
+        );
+
+        Ok(manager.finish(variables, order, root))
+    }
+
+    /// Builds the diagram of the solution, with the variables ordered as given.
+    ///
+    /// Returns [`Error::UnknownVariable`] if the solution contains a variable that is not in `variables`,
+    /// and an [`Error`] for the same bad `variables` as [`minimize`](crate::minimize).
+    pub fn from_solution<T: AsRef<str>>(
+        variables: &[T],
+        solution: &Solution,
+    ) -> Result<Self, Error> {
+        let variables = own_variables(variables);
+
+        validate_input(&variables, &HashSet::new(), &HashSet::new())?;
+
+        Bdd::from_solution_unchecked(variables, solution)
+    }
+
+    /// Builds the diagram of the solution without limiting the number and the names of the variables.
+    pub(crate) fn from_solution_unchecked(
+        variables: Vec<String>,
+        solution: &Solution,
+    ) -> Result<Self, Error> {
+        let order = (0..variables.len()).collect();
+        let mut manager = Manager::new(variables.len());
+        let root = match solution.expression() {
+            None if matches!(solution, Solution::One) => ONE,
+            None => ZERO,
+            Some((expression, form)) => {
+                let (inner, outer, empty) = match form {
+                    Form::SOP => (Operation::And, Operation::Or, ZERO),
+                    Form::POS => (Operation::Or, Operation::And, ONE),
+                };
+                let mut root = empty;
+
+                for term in expression {
+                    let mut node = if form == Form::SOP { ONE } else { ZERO };
+
+                    for variable in term {
+                        let level = variables
+                            .iter()
+                            .position(|name| *name == variable.name)
+                            .ok_or_else(|| Error::UnknownVariable(variable.name.clone()))?;
+                        let literal = if variable.is_negated {
+                            manager.make(level, ONE, ZERO)
+                        } else {
+                            manager.make(level, ZERO, ONE)
+                        };
+
+                        node = manager.apply(inner, node, literal, &mut HashMap::new());
+                    }
+
+                    root = manager.apply(outer, root, node, &mut HashMap::new());
+                }
+
+                root
+            }
+        };
+
+        Ok(manager.finish(variables, order, root))
+    }
+
+    /// Returns the variables in the order the bits of the terms refer to them,
+    /// the first variable being the most significant bit.
+    pub fn variables(&self) -> &[String] {
+        &self.variables
+    }
+
+    /// Returns the variables in the order they are tested from the root down.
+    pub fn order(&self) -> Vec<&str> {
+        self.order
+            .iter()
+            .map(|&index| self.variables[index].as_str())
+            .collect()
+    }
+
+    /// Rebuilds the diagram with the variables tested in the given order from the root down.
+    /// The variables missing from `order` are tested after the given ones in their current order.
+    ///
+    /// The terms of [`Bdd::evaluate`] still refer to the variables in the order of [`Bdd::variables`].
+    ///
+    /// Returns [`Error::UnknownVariable`] if `order` contains a variable that is not in the diagram
+    /// and [`Error::DuplicateVariables`] if it contains one more than once.
+    pub fn reorder<T: AsRef<str>>(&self, order: &[T]) -> Result<Self, Error> {
+        let mut new_order = vec![];
+        let mut duplicates = HashSet::new();
+
+        for name in order {
+            let name = name.as_ref();
+            let index = self
+                .variables
+                .iter()
+                .position(|variable| variable == name)
+                .ok_or_else(|| Error::UnknownVariable(name.to_owned()))?;
+
+            if new_order.contains(&index) {
+                duplicates.insert(name.to_owned());
+            }
+
+            new_order.push(index);
+        }
+
+        if !duplicates.is_empty() {
+            return Err(Error::DuplicateVariables(duplicates));
+        }
+
+        for &index in &self.order {
+            if !new_order.contains(&index) {
+                new_order.push(index);
+            }
+        }
+
+        let mut manager = Manager::new(self.variables.len());
+        let levels = self
+            .order
+            .iter()
+            .map(|index| new_order.iter().position(|i| i == index).unwrap())
+            .collect::<Vec<_>>();
+        let root = manager.import(self, &levels);
+
+        Ok(manager.finish(self.variables.clone(), new_order, root))
+    }
+
+    /// Returns the number of decision nodes, not counting the terminals 0 and 1.
+    pub fn node_count(&self) -> usize {
+        self.nodes.len() - 2
+    }
+
+    /// Evaluates the function for the given term, the first of [`Bdd::variables`] being the most significant bit.
+    pub fn evaluate(&self, term: u32) -> bool {
+        let variable_count = self.variables.len();
+
+        self.evaluate_with(|index| term >> (variable_count - index - 1) & 1 == 1)
+    }
+
+    /// Evaluates the function for the values of the variables in the order of [`Bdd::variables`].
+    pub(crate) fn evaluate_assignment(&self, values: &[bool]) -> bool {
+        self.evaluate_with(|index| values[index])
+    }
+
+    fn evaluate_with(&self, value: impl Fn(usize) -> bool) -> bool {
+        let mut node = self.root;
+
+        while node > ONE {
+            let Node { level, low, high } = self.nodes[node];
+
+            node = if value(self.order[level]) { high } else { low };
+        }
+
+        node == ONE
+    }
+
+    /// Returns the values of the variables in the order of [`Bdd::variables`] for which the function is 1,
+    /// the variables that are not tested on the way being 0, or `None` if the function is 0.
+    pub(crate) fn satisfying_assignment(&self) -> Option<Vec<bool>> {
+        let mut assignment = vec![false; self.variables.len()];
+        let mut node = self.root;
+
+        // Every decision node of a reduced diagram has a path to 1.
+        while node > ONE {
+            let Node { level, low, high } = self.nodes[node];
+            let is_high = low == ZERO;
+
+            assignment[self.order[level]] = is_high;
+            node = if is_high { high } else { low };
+        }
+
+        (node == ONE).then(|| assignment)
+    }
+
+    /// Combines the functions of the diagrams with the operation, keeping the variables and the order of `self`.
+    ///
+    /// Returns [`Error::UnknownVariable`] if `other` has a variable that `self` doesn't have.
+    pub fn apply(&self, other: &Bdd, operation: Operation) -> Result<Self, Error> {
+        let mut levels = vec![];
+
+        for &index in &other.order {
+            let name = &other.variables[index];
+            let index = self
+                .variables
+                .iter()
+                .position(|variable| variable == name)
+                .ok_or_else(|| Error::UnknownVariable(name.clone()))?;
+
+            levels.push(self.order.iter().position(|&i| i == index).unwrap());
+        }
+
+        let mut manager = Manager::from_bdd(self);
+        let other_root = manager.import(other, &levels);
+        let root = manager.apply(operation, self.root, other_root, &mut HashMap::new());
+
+        Ok(manager.finish(self.variables.clone(), self.order.clone(), root))
+    }
+
+    /// Returns the diagram of the negated function.
+    pub fn not(&self) -> Self {
+        let mut manager = Manager::from_bdd(self);
+        let root = manager.apply(Operation::Xor, self.root, ONE, &mut HashMap::new());
+
+        manager.finish(self.variables.clone(), self.order.clone(), root)
+    }
+
+    /// Renders the diagram in the [DOT](https://graphviz.org/doc/info/lang.html) language of Graphviz,
+    /// with dashed edges to the 0 cofactors and solid edges to the 1 cofactors.
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut dot = format!("digraph {} {{\n", quote(name));
+        let mut edges = vec![];
+
+        for (i, node) in self.nodes.iter().enumerate().skip(2).rev() {
+            let variable = &self.variables[self.order[node.level]];
+
+            writeln!(dot, "    n{} [label={}];", i, quote(variable)).unwrap();
+            edges.push(format!("n{} -> {} [style=dashed];", i, node_name(node.low)));
+            edges.push(format!("n{} -> {};", i, node_name(node.high)));
+        }
+
+        let is_used = |terminal| {
+            self.root == terminal
+                || self.nodes[2..]
+                    .iter()
+                    .any(|node| node.low == terminal || node.high == terminal)
+        };
+
+        if is_used(ZERO) {
+            dot.push_str("    zero [label=\"0\", shape=box];\n");
+        }
+
+        if is_used(ONE) {
+            dot.push_str("    one [label=\"1\", shape=box];\n");
+        }
+
+        if !edges.is_empty() {
+            dot.push('\n');
+        }
+
+        for edge in &edges {
+            writeln!(dot, "    {}", edge).unwrap();
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+}
+
+/// The nodes of diagrams under construction, shared so that equal subfunctions are represented by the same node.
+struct Manager {
+    level_count: usize,
+    nodes: Vec<Node>,
+    unique: HashMap<Node, usize>,
+}
+
+impl Manager {
+    fn new(level_count: usize) -> Self {
+        let terminal = Node {
+            level: level_count,
+            low: ZERO,
+            high: ZERO,
+        };
+
+        Manager {
+            level_count,
+            nodes: vec![
+                terminal,
+                Node {
+                    high: ONE,
+                    ..terminal
+                },
+            ],
+            unique: HashMap::new(),
+        }
+    }
+
+    fn from_bdd(bdd: &Bdd) -> Self {
+        let mut manager = Manager::new(bdd.variables.len());
+
+        for &node in &bdd.nodes[2..] {
+            manager.make(node.level, node.low, node.high);
+        }
+
+        manager
+    }
+
+    /// Returns the node testing the variable at `level`, reusing an existing one or skipping the test if possible.
+    fn make(&mut self, level: usize, low: usize, high: usize) -> usize {
+        if low == high {
+            return low;
+        }
+
+        let node = Node { level, low, high };
+        let nodes = &mut self.nodes;
+
+        *self.unique.entry(node).or_insert_with(|| {
+            nodes.push(node);
+            nodes.len() - 1
+        })
+    }
+
+    /// Builds the function of the terms from `level` down, `minterms` and `dont_cares` being terms of all variables.
+    fn build(&mut self, level: usize, minterms: Vec<u32>, dont_cares: Vec<u32>) -> usize {
+        if minterms.is_empty() {
+            return ZERO;
+        }
+
+        if minterms.len() + dont_cares.len() == 1 << (self.level_count - level) {
+            return ONE;
+        }
+
+        let bit = self.level_count - level - 1;
+        let split = |terms: Vec<u32>| -> (Vec<u32>, Vec<u32>) {
+            terms.into_iter().partition(|term| term >> bit & 1 == 0)
+        };
+        let (low_minterms, high_minterms) = split(minterms);
+        let (low_dont_cares, high_dont_cares) = split(dont_cares);
+        let full = 1 << (self.level_count - level - 1);
+
+        if low_dont_cares.len() == full {
+            return self.build(level + 1, high_minterms, high_dont_cares);
+        }
+
+        if high_dont_cares.len() == full {
+            return self.build(level + 1, low_minterms, low_dont_cares);
+        }
+
+        let low = self.build(level + 1, low_minterms, low_dont_cares);
+        let high = self.build(level + 1, high_minterms, high_dont_cares);
+
+        self.make(level, low, high)
+    }
+
+    fn apply(
+        &mut self,
+        operation: Operation,
+        a: usize,
+        b: usize,
+        cache: &mut HashMap<(usize, usize), usize>,
+    ) -> usize {
+        match (operation, a, b) {
+            (Operation::And, ZERO, _) | (Operation::And, _, ZERO) => return ZERO,
+            (Operation::And, ONE, other) | (Operation::And, other, ONE) => return other,
+            (Operation::Or, ONE, _) | (Operation::Or, _, ONE) => return ONE,
+            (Operation::Or, ZERO, other) | (Operation::Or, other, ZERO) => return other,
+            (Operation::Xor, ZERO, other) | (Operation::Xor, other, ZERO) => return other,
+            (Operation::Xor, a, b) if a == b => return ZERO,
+            (_, a, b) if a == b => return a,
+            _ => {}
+        }
+
+        if let Some(&node) = cache.get(&(a, b)) {
+            return node;
+        }
+
+        let (node_a, node_b) = (self.nodes[a], self.nodes[b]);
+        let level = node_a.level.min(node_b.level);
+        let cofactors = |node: Node, index: usize| {
+            if node.level == level {
+                (node.low, node.high)
+            } else {
+                (index, index)
+            }
+        };
+        let (low_a, high_a) = cofactors(node_a, a);
+        let (low_b, high_b) = cofactors(node_b, b);
+
+        let low = self.apply(operation, low_a, low_b, cache);
+        let high = self.apply(operation, high_a, high_b, cache);
+        let node = self.make(level, low, high);
+
+        cache.insert((a, b), node);
+
+        node
+    }
+
+    /// Copies the diagram into the manager with its levels mapped to `levels`, returning the new root.
+    fn import(&mut self, bdd: &Bdd, levels: &[usize]) -> usize {
+        let mut imported = vec![ZERO, ONE];
+
+        for node in &bdd.nodes[2..] {
+            let level = levels[node.level];
+            let (low, high) = (imported[node.low], imported[node.high]);
+            let variable = self.make(level, ZERO, ONE);
+            let negated = self.make(level, ONE, ZERO);
+
+            let high = self.apply(Operation::And, variable, high, &mut HashMap::new());
+            let low = self.apply(Operation::And, negated, low, &mut HashMap::new());
+
+            imported.push(self.apply(Operation::Or, high, low, &mut HashMap::new()));
+        }
+
+        imported[bdd.root]
+    }
+
+    /// Creates the diagram of the nodes reachable from `root`.
+    fn finish(self, variables: Vec<String>, order: Vec<usize>, root: usize) -> Bdd {
+        fn visit(
+            node: usize,
+            old: &[Node],
+            new: &mut Vec<Node>,
+            indices: &mut HashMap<usize, usize>,
+        ) -> usize {
+            if node <= ONE {
+                return node;
+            }
+
+            if let Some(&index) = indices.get(&node) {
+                return index;
+            }
+
+            let low = visit(old[node].low, old, new, indices);
+            let high = visit(old[node].high, old, new, indices);
+
+            new.push(Node {
+                level: old[node].level,
+                low,
+                high,
+            });
+            indices.insert(node, new.len() - 1);
+
+            new.len() - 1
+        }
+
+        let mut nodes = self.nodes[..2].to_vec();
+        let root = visit(root, &self.nodes, &mut nodes, &mut HashMap::new());
+
+        Bdd {
+            variables,
+            order,
+            nodes,
+            root,
+        }
+    }
+}
+
+fn node_name(node: usize) -> String {
+    match node {
+        ZERO => "zero".into(),
+        ONE => "one".into(),
+        _ => format!("n{}", node),
+    }
+}
@@ -0,0 +1,710 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::codegen::RUST_RULES;
+use crate::identifier::Namespace;
+use crate::{minimize, minimize_maxterms, minimize_minterms};
+use crate::{Error, Parameters, RustCode, Solution, DEFAULT_VARIABLES, SOP};
+
+/// Build script helper generating a Rust module from truth table, PLA and JSON spec files.
+///
+/// Each file becomes a module with a function for each of its outputs,
+/// generated from the first minimal solution with [`RustCode`].
+/// The module is named after the file stem, e.g. `decoder` for `logic/decoder.pla`.
+///
+/// The format of a file is determined by its extension:
+///
+/// * `.pla` -- Espresso PLA with the `.i`, `.o`, `.ilb`, `.ob`, `.p`, `.type` and `.e` keywords.
+///   The types `f`, `fd`, `fr` and `fdr` are supported, `fd` being the default.
+/// * `.json` -- An object with optional `name`, and `inputs` and `outputs`, each output having a `name`
+///   and either `minterms` and/or `maxterms`, or one of them with `dont_cares`.
+///   If `dont_cares` is given, the unlisted terms are maxterms or minterms respectively,
+///   otherwise they are don't care conditions.
+/// * Any other extension -- A truth table with a header row of input and output names separated by `|`,
+///   followed by a row for each term. Inputs can be `-` to match both values and outputs can be `-` for don't care.
+///   Unlisted terms are don't care conditions.
+///
+/// ```text
+/// # A 2-to-1 multiplexer.
+/// sel a b | y
+///   0 0 - | 0
+///   0 1 - | 1
+///   1 - 0 | 0
+///   1 - 1 | 1
+/// ```
+///
+/// In all formats except JSON, `#` starts a comment running to the end of the line,
+/// so names can't contain `#`.
+///
+/// # Example
+///
+/// In `build.rs`:
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+///
+/// use quine_mccluskey as qmc;
+///
+/// qmc::Build::new()
+///     .file("logic/mux.txt")
+///     .file("logic/decoder.pla")
+///     .timeout(Duration::from_secs(10))
+///     .compile("logic.rs");
+/// ```
+///
+/// And in the crate:
+///
+/// ```rust,ignore
+/// include!(concat!(env!("OUT_DIR"), "/logic.rs"));
+///
+/// assert!(mux::y(true, false, true));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Build {
+    files: Vec<PathBuf>,
+    timeout: Option<Duration>,
+    rust: RustCode,
+}
+
+/// Error types for [`Build`].
+#[derive(Debug, thiserror::Error)]
+pub enum BuildError {
+    /// Could not read a spec file or write the generated module.
+    #[error("{}: {source}", path.display())]
+    Io { path: PathBuf, source: io::Error },
+    /// A line of a spec file could not be parsed.
+    #[error("{}:{line}: {message}", path.display())]
+    Syntax {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+    /// A spec file was parsed but does not describe a valid set of boolean functions.
+    #[error("{}: {message}", path.display())]
+    Invalid { path: PathBuf, message: String },
+    /// Minimizing an output or generating its function failed.
+    #[error("{}: output `{output}`: {source}", path.display())]
+    Minimize {
+        path: PathBuf,
+        output: String,
+        source: Error,
+    },
+    /// `OUT_DIR` was not set, i.e. [`Build::compile`] was not called from a build script.
+    #[error("OUT_DIR is not set, the module can only be compiled from a build script.")]
+    OutDir,
+}
+
+impl Build {
+    pub fn new() -> Self {
+        Build::default()
+    }
+
+    /// Adds a spec file.
+    pub fn file<P: AsRef<Path>>(&mut self, path: P) -> &mut Self {
+        self.files.push(path.as_ref().to_owned());
+        self
+    }
+
+    /// Adds spec files.
+    pub fn files<P: AsRef<Path>>(&mut self, paths: impl IntoIterator<Item = P>) -> &mut Self {
+        for path in paths {
+            self.file(path);
+        }
+        self
+    }
+
+    /// Sets the timeout of minimizing each output.
+    pub fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets how the inputs are passed to the generated functions, [`Parameters::Bools`] by default.
+    pub fn parameters(&mut self, parameters: Parameters) -> &mut Self {
+        self.rust.parameters = parameters;
+        self
+    }
+
+    /// Writes the generated module to `output` in `OUT_DIR` and tells Cargo to rerun the build script
+    /// if a spec file changes.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a readable message on any [`BuildError`], failing the build.
+    pub fn compile(&self, output: &str) {
+        if let Err(error) = self.try_compile(output) {
+            panic!("{}", error);
+        }
+    }
+
+    /// Same as [`Build::compile`], but returns the [`BuildError`] instead of panicking,
+    /// and the path of the written module on success.
+    pub fn try_compile(&self, output: &str) -> Result<PathBuf, BuildError> {
+        for path in &self.files {
+            println!("cargo:rerun-if-changed={}", path.display());
+        }
+
+        let path = PathBuf::from(env::var_os("OUT_DIR").ok_or(BuildError::OutDir)?).join(output);
+        let rust = self.generate()?;
+
+        fs::write(&path, rust).map_err(|source| BuildError::Io {
+            path: path.clone(),
+            source,
+        })?;
+
+        Ok(path)
+    }
+
+    /// Generates the module without writing it.
+    pub fn generate(&self) -> Result<String, BuildError> {
+        let mut rust = String::from("// Generated by quine-mccluskey. Do not edit.\n");
+        let mut modules = Namespace::new(&RUST_RULES);
+
+        for path in &self.files {
+            let text = fs::read_to_string(path).map_err(|source| BuildError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            let spec = Spec::parse(path, &text)?;
+            let mut functions = Namespace::new(&RUST_RULES);
+
+            writeln!(
+                rust,
+                "\n// Generated from `{}`.\npub mod {} {{",
+                path.display(),
+                modules.insert(&spec.name)
+            )
+            .unwrap();
+
+            for (i, (output, terms)) in spec.outputs.iter().enumerate() {
+                let error = |source| BuildError::Minimize {
+                    path: path.clone(),
+                    output: output.clone(),
+                    source,
+                };
+
+                let solution = terms.minimize(&spec.inputs, self.timeout).map_err(error)?;
+                let function = self
+                    .rust
+                    .function(&functions.insert(output), &spec.inputs, &solution)
+                    .map_err(error)?;
+
+                if i > 0 {
+                    rust.push('\n');
+                }
+
+                for line in function.lines() {
+                    writeln!(rust, "    {}", line).unwrap();
+                }
+            }
+
+            rust.push_str("}\n");
+        }
+
+        Ok(rust)
+    }
+}
+
+/// The boolean functions described by a spec file.
+struct Spec {
+    name: String,
+    inputs: Vec<String>,
+    outputs: Vec<(String, Terms)>,
+}
+
+enum Terms {
+    /// The unlisted terms are maxterms.
+    Minterms {
+        minterms: Vec<u32>,
+        dont_cares: Vec<u32>,
+    },
+    /// The unlisted terms are minterms.
+    Maxterms {
+        maxterms: Vec<u32>,
+        dont_cares: Vec<u32>,
+    },
+    /// The unlisted terms are don't cares.
+    Both {
+        minterms: Vec<u32>,
+        maxterms: Vec<u32>,
+    },
+}
+
+impl Terms {
+    fn minimize(&self, variables: &[String], timeout: Option<Duration>) -> Result<Solution, Error> {
+        let mut solutions = match self {
+            Terms::Minterms {
+                minterms,
+                dont_cares,
+            } => minimize_minterms(variables, minterms, dont_cares, false, timeout),
+            Terms::Maxterms {
+                maxterms,
+                dont_cares,
+            } => minimize_maxterms(variables, maxterms, dont_cares, false, timeout),
+            Terms::Both { minterms, maxterms } => {
+                minimize(variables, minterms, maxterms, SOP, false, timeout)
+            }
+        }?;
+
+        Ok(solutions.pop().unwrap())
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonSpec {
+    name: Option<String>,
+    inputs: Vec<String>,
+    outputs: Vec<JsonOutput>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct JsonOutput {
+    name: String,
+    minterms: Option<Vec<u32>>,
+    maxterms: Option<Vec<u32>>,
+    dont_cares: Option<Vec<u32>>,
+}
+
+impl Spec {
+    fn parse(path: &Path, text: &str) -> Result<Self, BuildError> {
+        let name = path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        let spec = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Spec::parse_json(path, name, text)?,
+            Some("pla") => Spec::parse_pla(path, name, text)?,
+            _ => Spec::parse_truth_table(path, name, text)?,
+        };
+
+        let mut outputs = spec
+            .outputs
+            .iter()
+            .map(|(output, _)| output)
+            .collect::<Vec<_>>();
+        outputs.sort_unstable();
+
+        if let Some(pair) = outputs.windows(2).find(|pair| pair[0] == pair[1]) {
+            return Err(BuildError::Invalid {
+                path: path.to_owned(),
+                message: format!("duplicate output `{}`", pair[0]),
+            });
+        }
+
+        Ok(spec)
+    }
+
+    fn parse_json(path: &Path, name: String, text: &str) -> Result<Self, BuildError> {
+        let spec: JsonSpec = serde_json::from_str(text).map_err(|error| BuildError::Syntax {
+            path: path.to_owned(),
+            line: error.line(),
+            message: error.to_string(),
+        })?;
+
+        let outputs = spec
+            .outputs
+            .into_iter()
+            .map(|output| {
+                let terms = match (output.minterms, output.maxterms, output.dont_cares) {
+                    (Some(minterms), None, dont_cares) => Terms::Minterms {
+                        minterms,
+                        dont_cares: dont_cares.unwrap_or_default(),
+                    },
+                    (None, Some(maxterms), dont_cares) => Terms::Maxterms {
+                        maxterms,
+                        dont_cares: dont_cares.unwrap_or_default(),
+                    },
+                    (Some(minterms), Some(maxterms), None) => Terms::Both { minterms, maxterms },
+                    (minterms, _, _) => {
+                        return Err(BuildError::Invalid {
+                            path: path.to_owned(),
+                            message: format!(
+                                "output `{}`: {}",
+                                output.name,
+                                if minterms.is_none() {
+                                    "expected `minterms` or `maxterms`"
+                                } else {
+                                    "`dont_cares` can't be given with both `minterms` and `maxterms`"
+                                }
+                            ),
+                        })
+                    }
+                };
+
+                Ok((output.name, terms))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Spec {
+            name: spec.name.unwrap_or(name),
+            inputs: spec.inputs,
+            outputs,
+        })
+    }
+
+    fn parse_truth_table(path: &Path, name: String, text: &str) -> Result<Self, BuildError> {
+        let mut header = None;
+        let mut table = Table::default();
+
+        for (line, text) in lines(text) {
+            let syntax = |message: String| BuildError::Syntax {
+                path: path.to_owned(),
+                line,
+                message,
+            };
+
+            let (inputs, outputs) = text
+                .split_once('|')
+                .ok_or_else(|| syntax("expected `|` between the inputs and the outputs".into()))?;
+
+            let (input_names, output_names) = match &header {
+                Some(header) => header,
+                None => {
+                    let names = |names: &str| {
+                        names
+                            .split_whitespace()
+                            .map(ToOwned::to_owned)
+                            .collect::<Vec<_>>()
+                    };
+
+                    let (inputs, outputs) = (names(inputs), names(outputs));
+
+                    if inputs.is_empty() || outputs.is_empty() {
+                        return Err(syntax("expected input and output names".into()));
+                    }
+
+                    if inputs.len() > DEFAULT_VARIABLES.len() {
+                        return Err(syntax(too_many_inputs(inputs.len())));
+                    }
+
+                    table = Table::new(outputs.len());
+                    header = Some((inputs, outputs));
+                    continue;
+                }
+            };
+
+            let cube = row_values(inputs, input_names.len(), "input").map_err(syntax)?;
+            let values = row_values(outputs, output_names.len(), "output").map_err(syntax)?;
+            let terms =
+                cube_terms(&cube).ok_or_else(|| syntax(format!("invalid inputs `{}`", cube)))?;
+
+            for (i, value) in values.chars().enumerate() {
+                let value = match value {
+                    '1' => Value::One,
+                    '0' => Value::Zero,
+                    '-' => Value::DontCare,
+                    _ => return Err(syntax(format!("invalid outputs `{}`", values))),
+                };
+
+                table
+                    .set(i, &terms, value, line, &output_names[i])
+                    .map_err(syntax)?;
+            }
+        }
+
+        let (inputs, outputs) = header.ok_or_else(|| BuildError::Invalid {
+            path: path.to_owned(),
+            message: "expected a header row of input and output names".into(),
+        })?;
+
+        Ok(Spec {
+            name,
+            inputs,
+            outputs: outputs.into_iter().zip(table.terms(true)).collect(),
+        })
+    }
+
+    fn parse_pla(path: &Path, name: String, text: &str) -> Result<Self, BuildError> {
+        let mut input_count = None;
+        let mut output_count = None;
+        let mut input_names: Option<(usize, Vec<String>)> = None;
+        let mut output_names: Option<(usize, Vec<String>)> = None;
+        let mut pla_type = "fd".to_owned();
+        let mut table = None;
+
+        for (line, text) in lines(text) {
+            let syntax = |message: String| BuildError::Syntax {
+                path: path.to_owned(),
+                line,
+                message,
+            };
+            let count = |argument: Option<&str>| {
+                argument
+                    .and_then(|argument| argument.parse::<usize>().ok())
+                    .ok_or_else(|| syntax("expected a number".into()))
+            };
+
+            if text.starts_with('.') {
+                let mut words = text.split_whitespace();
+                let keyword = words.next().unwrap();
+
+                match keyword {
+                    ".i" => {
+                        let count = count(words.next())?;
+
+                        if count > DEFAULT_VARIABLES.len() {
+                            return Err(syntax(too_many_inputs(count)));
+                        }
+
+                        input_count = Some(count);
+                    }
+                    ".o" => output_count = Some(count(words.next())?),
+                    ".ilb" => input_names = Some((line, words.map(ToOwned::to_owned).collect())),
+                    ".ob" => output_names = Some((line, words.map(ToOwned::to_owned).collect())),
+                    ".p" => {
+                        count(words.next())?;
+                    }
+                    ".type" => match words.next() {
+                        Some(argument @ ("f" | "fd" | "fr" | "fdr")) => pla_type = argument.into(),
+                        _ => return Err(syntax("expected type `f`, `fd`, `fr` or `fdr`".into())),
+                    },
+                    ".e" | ".end" => break,
+                    _ => return Err(syntax(format!("unsupported keyword `{}`", keyword))),
+                }
+
+                continue;
+            }
+
+            let (input_count, output_count) = match (input_count, output_count) {
+                (Some(input_count), Some(output_count)) => (input_count, output_count),
+                _ => {
+                    return Err(syntax(
+                        "expected `.i` and `.o` before the first product term".into(),
+                    ))
+                }
+            };
+
+            let row = text.split_whitespace().collect::<String>();
+
+            if row.len() != input_count + output_count || !row.is_ascii() {
+                return Err(syntax(format!(
+                    "expected {} inputs and {} outputs",
+                    input_count, output_count
+                )));
+            }
+
+            let (cube, values) = row.split_at(input_count);
+            let terms =
+                cube_terms(cube).ok_or_else(|| syntax(format!("invalid inputs `{}`", cube)))?;
+            let table = table.get_or_insert_with(|| Table::new(output_count));
+
+            for (i, value) in values.chars().enumerate() {
+                let value = match value {
+                    '1' => Value::One,
+                    '0' if pla_type.contains('r') => Value::Zero,
+                    '-' if pla_type.contains('d') => Value::DontCare,
+                    '0' | '-' | '~' => continue,
+                    _ => return Err(syntax(format!("invalid outputs `{}`", values))),
+                };
+
+                let output_name = match &output_names {
+                    Some((_, names)) => names.get(i).cloned().unwrap_or_default(),
+                    None => format!("f{}", i),
+                };
+
+                table
+                    .set(i, &terms, value, line, &output_name)
+                    .map_err(syntax)?;
+            }
+        }
+
+        let invalid = |message: String| BuildError::Invalid {
+            path: path.to_owned(),
+            message,
+        };
+
+        let input_count = input_count.ok_or_else(|| invalid("expected `.i`".into()))?;
+        let output_count = output_count.ok_or_else(|| invalid("expected `.o`".into()))?;
+
+        let names = |names: Option<(usize, Vec<String>)>,
+                     count: usize,
+                     default: &dyn Fn(usize) -> String| {
+            match names {
+                Some((line, names)) if names.len() != count => Err(BuildError::Syntax {
+                    path: path.to_owned(),
+                    line,
+                    message: format!("expected {} names", count),
+                }),
+                Some((_, names)) => Ok(names),
+                None => Ok((0..count).map(default).collect()),
+            }
+        };
+
+        let inputs = names(input_names, input_count, &|i| {
+            DEFAULT_VARIABLES
+                .get(i)
+                .map_or_else(|| format!("x{}", i), |&name| name.to_owned())
+        })?;
+        let outputs = names(output_names, output_count, &|i| format!("f{}", i))?;
+        let table = table.unwrap_or_else(|| Table::new(output_count));
+
+        Ok(Spec {
+            name,
+            inputs,
+            outputs: outputs
+                .into_iter()
+                .zip(table.terms(pla_type.contains('r')))
+                .collect(),
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Value {
+    One,
+    Zero,
+    DontCare,
+}
+
+/// The values of the outputs of a table and the lines they are set on.
+#[derive(Default)]
+struct Table {
+    outputs: Vec<HashMap<u32, (Value, usize)>>,
+}
+
+impl Table {
+    fn new(output_count: usize) -> Self {
+        Table {
+            outputs: vec![HashMap::new(); output_count],
+        }
+    }
+
+    /// Sets the value of the output for the terms. A one or zero overrides a don't care but not the other.
+    fn set(
+        &mut self,
+        output: usize,
+        terms: &[u32],
+        value: Value,
+        line: usize,
+        output_name: &str,
+    ) -> Result<(), String> {
+        for &term in terms {
+            match self.outputs[output].get(&term) {
+                Some(&(previous, previous_line)) if previous != value => {
+                    if value == Value::DontCare {
+                        continue;
+                    }
+
+                    if previous != Value::DontCare {
+                        return Err(format!(
+                            "term {} of output `{}` is {} here but {} on line {}",
+                            term,
+                            output_name,
+                            value.as_char(),
+                            previous.as_char(),
+                            previous_line
+                        ));
+                    }
+                }
+                Some(_) => continue,
+                None => {}
+            }
+
+            self.outputs[output].insert(term, (value, line));
+        }
+
+        Ok(())
+    }
+
+    /// Collects the terms of each output. The unlisted terms are don't cares or zeros.
+    fn terms(self, unlisted_dont_care: bool) -> Vec<Terms> {
+        self.outputs
+            .into_iter()
+            .map(|values| {
+                let collect = |kind: Value| {
+                    let mut terms = values
+                        .iter()
+                        .filter(|(_, &(value, _))| value == kind)
+                        .map(|(&term, _)| term)
+                        .collect::<Vec<_>>();
+                    terms.sort_unstable();
+                    terms
+                };
+
+                if unlisted_dont_care {
+                    Terms::Both {
+                        minterms: collect(Value::One),
+                        maxterms: collect(Value::Zero),
+                    }
+                } else {
+                    Terms::Minterms {
+                        minterms: collect(Value::One),
+                        dont_cares: collect(Value::DontCare),
+                    }
+                }
+            })
+            .collect()
+    }
+}
+
+impl Value {
+    fn as_char(self) -> char {
+        match self {
+            Value::One => '1',
+            Value::Zero => '0',
+            Value::DontCare => '-',
+        }
+    }
+}
+
+/// Returns the non-empty lines with their line numbers, without the comments from `#` to the end of the line.
+fn lines(text: &str) -> impl Iterator<Item = (usize, &str)> {
+    text.lines().enumerate().filter_map(|(i, line)| {
+        let line = line.split('#').next().unwrap().trim();
+
+        if line.is_empty() {
+            None
+        } else {
+            Some((i + 1, line))
+        }
+    })
+}
+
+fn too_many_inputs(count: usize) -> String {
+    format!(
+        "expected at most {} inputs, found {}",
+        DEFAULT_VARIABLES.len(),
+        count
+    )
+}
+
+fn row_values(values: &str, count: usize, kind: &str) -> Result<String, String> {
+    let values = values.split_whitespace().collect::<String>();
+
+    if values.chars().count() == count {
+        Ok(values)
+    } else {
+        Err(format!("expected {} {}s", count, kind))
+    }
+}
+
+/// Returns the terms covered by the cube, e.g. `1-0` covers 4 and 6.
+fn cube_terms(cube: &str) -> Option<Vec<u32>> {
+    let mut terms = vec![0];
+
+    for char in cube.chars() {
+        match char {
+            '0' => terms.iter_mut().for_each(|term| *term <<= 1),
+            '1' => terms.iter_mut().for_each(|term| *term = *term << 1 | 1),
+            '-' => {
+                terms = terms
+                    .iter()
+                    .flat_map(|&term| [term << 1, term << 1 | 1])
+                    .collect()
+            }
+            _ => return None,
+        }
+    }
+
+    Some(terms)
+}
@@ -0,0 +1,238 @@
+use std::fmt::{Display, Write};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::implicant::Implicant;
+use crate::prime_implicant_chart::PrimeImplicantChart;
+use crate::progress::ProgressNoOp;
+use crate::timeout_signal::TimeoutSignalNoOp;
+
+/// A renderable snapshot of the prime implicant chart.
+///
+/// Rows are the prime implicants in cube notation and columns are the care terms.
+/// Rows that are extracted as essential and rows and columns that are removed
+/// based on dominance during simplification are flagged.
+///
+/// [`Display`] renders the chart as plain text, see [`ChartTable::to_text`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartTable {
+    pub columns: Vec<ChartColumn>,
+    pub rows: Vec<ChartRow>,
+}
+
+/// A column of a [`ChartTable`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartColumn {
+    pub term: u32,
+    /// The column was removed because it dominates another column.
+    pub is_dominating: bool,
+}
+
+/// A row of a [`ChartTable`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ChartRow {
+    /// The prime implicant in cube notation, e.g. `0-1`.
+    pub implicant: String,
+    /// Whether the prime implicant covers the term of the column with the same index.
+    pub marks: Vec<bool>,
+    /// The row was extracted as an essential prime implicant.
+    pub is_essential: bool,
+    /// The row was removed because another row dominates it.
+    pub is_dominated: bool,
+}
+
+impl ChartTable {
+    pub(crate) fn new(chart: &PrimeImplicantChart, variable_count: u32) -> Self {
+        let mut simplified_chart = chart.clone();
+        simplified_chart
+            .simplify(false, &TimeoutSignalNoOp, &ProgressNoOp)
+            .expect("simplification without timeout can't fail");
+
+        let mut terms = chart
+            .terms()
+            .iter()
+            .map(Implicant::value)
+            .enumerate()
+            .collect::<Vec<_>>();
+        terms.sort_unstable_by_key(|&(_, term)| term);
+
+        let mut implicants = chart
+            .implicants()
+            .iter()
+            .copied()
+            .enumerate()
+            .collect::<Vec<_>>();
+        implicants.sort_unstable_by_key(|&(_, implicant)| implicant);
+
+        let columns = terms
+            .iter()
+            .map(|&(_, term)| ChartColumn {
+                term,
+                is_dominating: simplified_chart
+                    .dominating_terms()
+                    .contains(&Implicant::new(term)),
+            })
+            .collect();
+
+        let rows = implicants
+            .iter()
+            .map(|&(y, implicant)| ChartRow {
+                implicant: implicant.to_str(variable_count),
+                marks: terms.iter().map(|&(x, _)| chart.is_marked(y, x)).collect(),
+                is_essential: simplified_chart
+                    .essential_prime_implicants()
+                    .contains(&implicant),
+                is_dominated: simplified_chart.dominated_implicants().contains(&implicant),
+            })
+            .collect();
+
+        ChartTable { columns, rows }
+    }
+
+    /// Renders the chart as a plain text table.
+    ///
+    /// Essential rows are prefixed with `*` and rows removed by dominance with `~`.
+    /// Columns removed by dominance are marked with `~` in an extra row at the bottom.
+    pub fn to_text(&self) -> String {
+        let implicant_width = self
+            .rows
+            .iter()
+            .map(|row| row.implicant.chars().count())
+            .max()
+            .unwrap_or(0);
+        let widths = self.column_widths();
+        let mut text = String::new();
+
+        write!(text, "  {:implicant_width$} |", "").unwrap();
+        for (column, width) in self.columns.iter().zip(&widths) {
+            write!(text, " {:>width$} |", column.term).unwrap();
+        }
+        text.push('\n');
+
+        for row in &self.rows {
+            let flag = if row.is_essential {
+                '*'
+            } else if row.is_dominated {
+                '~'
+            } else {
+                ' '
+            };
+
+            write!(text, "{} {:implicant_width$} |", flag, row.implicant).unwrap();
+            for (&is_marked, width) in row.marks.iter().zip(&widths) {
+                write!(text, " {:>width$} |", if is_marked { "X" } else { "" }).unwrap();
+            }
+            text.push('\n');
+        }
+
+        if self.columns.iter().any(|column| column.is_dominating) {
+            write!(text, "  {:implicant_width$} |", "").unwrap();
+            for (column, width) in self.columns.iter().zip(&widths) {
+                let flag = if column.is_dominating { "~" } else { "" };
+                write!(text, " {:>width$} |", flag).unwrap();
+            }
+            text.push('\n');
+        }
+
+        text
+    }
+
+    /// Renders the chart as a Markdown table.
+    ///
+    /// Essential rows are in bold and rows and columns removed by dominance are struck through.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::from("| Implicant |");
+
+        for column in &self.columns {
+            if column.is_dominating {
+                write!(markdown, " ~~{}~~ |", column.term).unwrap();
+            } else {
+                write!(markdown, " {} |", column.term).unwrap();
+            }
+        }
+
+        markdown.push_str("\n|:-|");
+        markdown.push_str(&":-:|".repeat(self.columns.len()));
+        markdown.push('\n');
+
+        for row in &self.rows {
+            if row.is_essential {
+                write!(markdown, "| **{}** |", row.implicant).unwrap();
+            } else if row.is_dominated {
+                write!(markdown, "| ~~{}~~ |", row.implicant).unwrap();
+            } else {
+                write!(markdown, "| {} |", row.implicant).unwrap();
+            }
+
+            for &is_marked in &row.marks {
+                markdown.push_str(if is_marked { " X |" } else { "  |" });
+            }
+
+            markdown.push('\n');
+        }
+
+        markdown
+    }
+
+    /// Renders the chart as an HTML table.
+    ///
+    /// Essential rows have the class `essential`, rows removed because another row dominates them
+    /// have the class `dominated` and columns removed because they dominate another column
+    /// have the class `dominating`, so they can be highlighted with CSS.
+    pub fn to_html(&self) -> String {
+        let mut html = String::from("<table>\n  <tr>\n    <th>Implicant</th>\n");
+
+        for column in &self.columns {
+            if column.is_dominating {
+                writeln!(html, "    <th class=\"dominating\">{}</th>", column.term).unwrap();
+            } else {
+                writeln!(html, "    <th>{}</th>", column.term).unwrap();
+            }
+        }
+
+        html.push_str("  </tr>\n");
+
+        for row in &self.rows {
+            if row.is_essential {
+                html.push_str("  <tr class=\"essential\">\n");
+            } else if row.is_dominated {
+                html.push_str("  <tr class=\"dominated\">\n");
+            } else {
+                html.push_str("  <tr>\n");
+            }
+
+            writeln!(html, "    <td>{}</td>", row.implicant).unwrap();
+
+            for &is_marked in &row.marks {
+                html.push_str(if is_marked {
+                    "    <td>X</td>\n"
+                } else {
+                    "    <td></td>\n"
+                });
+            }
+
+            html.push_str("  </tr>\n");
+        }
+
+        html.push_str("</table>\n");
+
+        html
+    }
+
+    fn column_widths(&self) -> Vec<usize> {
+        self.columns
+            .iter()
+            .map(|column| column.term.to_string().len())
+            .collect()
+    }
+}
+
+impl Display for ChartTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
@@ -0,0 +1,414 @@
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::identifier::{validate_ports, IdentifierRules, Namespace};
+use crate::{Error, DEFAULT_VARIABLES};
+use crate::{Negation, Parentheses, Solution, Style, Variable};
+
+#[rustfmt::skip]
+pub(crate) static RUST_RULES: IdentifierRules = IdentifierRules {
+    keywords: &[
+        "Self", "abstract", "as", "async", "await", "become", "box", "break", "const", "continue",
+        "crate", "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "gen", "if",
+        "impl", "in", "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv",
+        "pub", "ref", "return", "self", "static", "struct", "super", "trait", "true", "try",
+        "type", "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
+    ],
+    is_case_insensitive: false,
+    has_strict_underscores: false,
+};
+
+#[rustfmt::skip]
+static C_RULES: IdentifierRules = IdentifierRules {
+    keywords: &[
+        "alignas", "alignof", "auto", "bool", "break", "case", "char", "const", "constexpr",
+        "continue", "default", "do", "double", "else", "enum", "extern", "false", "float", "for",
+        "goto", "if", "inline", "int", "long", "nullptr", "register", "restrict", "return", "short",
+        "signed", "sizeof", "static", "static_assert", "struct", "switch", "thread_local", "true",
+        "typedef", "typeof", "typeof_unqual", "union", "unsigned", "void", "volatile", "while",
+    ],
+    is_case_insensitive: false,
+    has_strict_underscores: false,
+};
+
+/// How the inputs are passed to a generated function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Parameters {
+    /// One boolean parameter per input.
+    Bools,
+    /// A single integer parameter `x` whose bits are the inputs, in the same order as the bits of the terms.
+    /// The first input is the most significant bit.
+    Bits,
+}
+
+/// Rust code generation options for [`Solution`]s.
+///
+/// Names that aren't legal Rust identifiers are sanitized, e.g. `a+b` becomes `a_b`.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["sel", "a", "b"];
+/// let solution = qmc::minimize(&variables, &[2, 3, 5, 7], &[0, 1, 4, 6], qmc::SOP, false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// assert_eq!(
+///     qmc::RustCode::default().function("mux", &variables, &solution).unwrap(),
+///     "pub fn mux(sel: bool, a: bool, b: bool) -> bool {\n    (sel && b) || (!sel && a)\n}\n"
+/// );
+///
+/// let rust = qmc::RustCode {
+///     parameters: qmc::Parameters::Bits,
+/// };
+///
+/// assert_eq!(
+///     rust.function("mux", &variables, &solution).unwrap(),
+///     "pub fn mux(x: u32) -> bool {\n    \
+///      ((x >> 2) & 1 != 0 && x & 1 != 0) || ((x >> 2) & 1 == 0 && (x >> 1) & 1 != 0)\n}\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct RustCode {
+    pub parameters: Parameters,
+}
+
+/// C code generation options for [`Solution`]s.
+///
+/// Names that aren't legal C identifiers are sanitized, e.g. `a+b` becomes `a_b`.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["sel", "a", "b"];
+/// let solution = qmc::minimize(&variables, &[2, 3, 5, 7], &[0, 1, 4, 6], qmc::SOP, false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// assert_eq!(
+///     qmc::CCode::default().function("mux", &variables, &solution).unwrap(),
+///     "static inline int mux(int sel, int a, int b) {\n    return (sel && b) || (!sel && a);\n}\n"
+/// );
+///
+/// let c = qmc::CCode {
+///     is_macro: true,
+///     ..Default::default()
+/// };
+///
+/// assert_eq!(
+///     c.function("MUX", &variables, &solution).unwrap(),
+///     "#define MUX(sel, a, b) (((sel) && (b)) || (!(sel) && (a)))\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CCode {
+    pub parameters: Parameters,
+    /// Generate a function-like macro instead of a `static inline` function.
+    pub is_macro: bool,
+}
+
+impl RustCode {
+    /// Generates a function returning the value of the [`Solution`] for the given `inputs`.
+    ///
+    /// Returns [`Error::DuplicateVariables`] if an input is given more than once,
+    /// [`Error::UnknownVariable`] if the solution contains a variable that is not in `inputs`
+    /// and [`Error::InvalidVariableCount`] if there are more inputs than bits in the parameter.
+    pub fn function<T: AsRef<str>>(
+        &self,
+        name: &str,
+        inputs: &[T],
+        solution: &Solution,
+    ) -> Result<String, Error> {
+        let (parameters, solution) = self.parts(inputs, solution)?;
+        let name = RUST_RULES.sanitize(name);
+        let mut rust = String::new();
+
+        if name
+            .chars()
+            .chain(parameters.chars())
+            .any(|char| char.is_ascii_uppercase())
+        {
+            rust.push_str("#[allow(non_snake_case)]\n");
+        }
+
+        rust.push_str(&format!(
+            "pub fn {}({}) -> bool {{\n    {}\n}}\n",
+            name,
+            parameters,
+            solution.format(&rust_style())
+        ));
+
+        Ok(rust)
+    }
+
+    /// Generates only the expression of the function generated by [`RustCode::function`],
+    /// e.g. `(sel && b) || (!sel && a)`.
+    ///
+    /// Returns an [`Error`] for the same bad input as [`RustCode::function`].
+    pub fn expression<T: AsRef<str>>(
+        &self,
+        inputs: &[T],
+        solution: &Solution,
+    ) -> Result<String, Error> {
+        let (_, solution) = self.parts(inputs, solution)?;
+
+        Ok(solution.format(&rust_style()))
+    }
+
+    /// Returns the parameter list and the solution with the variables replaced by their identifiers or bits.
+    fn parts<T: AsRef<str>>(
+        &self,
+        inputs: &[T],
+        solution: &Solution,
+    ) -> Result<(String, Solution), Error> {
+        let inputs = validate(inputs, solution)?;
+        let used_variables = used_variables(solution);
+        let mut namespace = Namespace::new(&RUST_RULES);
+
+        Ok(match self.parameters {
+            Parameters::Bools => {
+                // Unused parameters are prefixed with an underscore to avoid warnings.
+                let identifiers = inputs
+                    .iter()
+                    .map(|&input| {
+                        if used_variables.contains(&input) {
+                            (input, namespace.insert(input))
+                        } else {
+                            (input, namespace.insert(&format!("_{}", input)))
+                        }
+                    })
+                    .collect::<HashMap<_, _>>();
+                let parameters = inputs
+                    .iter()
+                    .map(|input| format!("{}: bool", identifiers[input]))
+                    .collect::<Vec<_>>();
+
+                (
+                    parameters.join(", "),
+                    solution.rename_variables(|variable| identifiers[variable].clone()),
+                )
+            }
+            Parameters::Bits => {
+                let unused = if used_variables.is_empty() { "_" } else { "" };
+                let solution = bit_literals(solution, &inputs, |index, is_negated| {
+                    let bit = if index == 0 {
+                        "x & 1".to_owned()
+                    } else {
+                        format!("(x >> {}) & 1", index)
+                    };
+
+                    format!("{} {} 0", bit, if is_negated { "==" } else { "!=" })
+                });
+
+                (format!("{}x: u32", unused), solution)
+            }
+        })
+    }
+}
+
+impl CCode {
+    /// Generates a function or macro returning the value of the [`Solution`] for the given `inputs`.
+    ///
+    /// With [`Parameters::Bits`], the parameter is an `unsigned long`.
+    ///
+    /// Returns [`Error::DuplicateVariables`] if an input is given more than once,
+    /// [`Error::UnknownVariable`] if the solution contains a variable that is not in `inputs`
+    /// and [`Error::InvalidVariableCount`] if there are more inputs than bits in the parameter.
+    pub fn function<T: AsRef<str>>(
+        &self,
+        name: &str,
+        inputs: &[T],
+        solution: &Solution,
+    ) -> Result<String, Error> {
+        let inputs = validate(inputs, solution)?;
+        let mut namespace = Namespace::new(&C_RULES);
+        let argument = |parameter: &str| {
+            if self.is_macro {
+                format!("({})", parameter)
+            } else {
+                parameter.to_owned()
+            }
+        };
+
+        let (parameters, solution) = match self.parameters {
+            Parameters::Bools => {
+                let identifiers = identifiers(&inputs, &mut namespace);
+                let parameters = inputs
+                    .iter()
+                    .map(|input| {
+                        if self.is_macro {
+                            identifiers[input].clone()
+                        } else {
+                            format!("int {}", identifiers[input])
+                        }
+                    })
+                    .collect::<Vec<_>>();
+
+                (
+                    parameters,
+                    solution.rename_variables(|variable| argument(&identifiers[variable])),
+                )
+            }
+            Parameters::Bits => {
+                let x = argument("x");
+                let solution = bit_literals(solution, &inputs, |index, is_negated| {
+                    let bit = if index == 0 {
+                        format!("({} & 1)", x)
+                    } else {
+                        format!("(({} >> {}) & 1)", x, index)
+                    };
+
+                    format!("{}{}", if is_negated { "!" } else { "" }, bit)
+                });
+
+                let parameter = if self.is_macro {
+                    "x"
+                } else {
+                    "unsigned long x"
+                };
+
+                (vec![parameter.to_owned()], solution)
+            }
+        };
+
+        let name = C_RULES.sanitize(name);
+        let expression = solution.format(&c_style());
+
+        if self.is_macro {
+            Ok(format!(
+                "#define {}({}) ({})\n",
+                name,
+                parameters.join(", "),
+                expression
+            ))
+        } else {
+            Ok(format!(
+                "static inline int {}({}) {{\n    return {};\n}}\n",
+                name,
+                if parameters.is_empty() {
+                    "void".to_owned()
+                } else {
+                    parameters.join(", ")
+                },
+                expression
+            ))
+        }
+    }
+}
+
+impl Default for RustCode {
+    fn default() -> Self {
+        RustCode {
+            parameters: Parameters::Bools,
+        }
+    }
+}
+
+impl Default for CCode {
+    fn default() -> Self {
+        CCode {
+            parameters: Parameters::Bools,
+            is_macro: false,
+        }
+    }
+}
+
+fn validate<'a, T: AsRef<str>>(
+    inputs: &'a [T],
+    solution: &Solution,
+) -> Result<Vec<&'a str>, Error> {
+    let inputs = inputs.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+
+    if inputs.len() > DEFAULT_VARIABLES.len() {
+        return Err(Error::InvalidVariableCount(inputs.len()));
+    }
+
+    validate_ports(&inputs, &[], [solution].into_iter())?;
+
+    Ok(inputs)
+}
+
+fn identifiers<'a>(inputs: &[&'a str], namespace: &mut Namespace) -> HashMap<&'a str, String> {
+    inputs
+        .iter()
+        .map(|&input| (input, namespace.insert(input)))
+        .collect()
+}
+
+fn used_variables(solution: &Solution) -> Vec<&str> {
+    match solution.expression() {
+        Some((expression, _)) => expression
+            .iter()
+            .flatten()
+            .map(|variable| variable.name.as_str())
+            .collect(),
+        None => vec![],
+    }
+}
+
+/// Replaces each variable with a literal on its bit of the term, which already includes the negation.
+fn bit_literals(
+    solution: &Solution,
+    inputs: &[&str],
+    literal: impl Fn(usize, bool) -> String,
+) -> Solution {
+    let replace = |expression: &Vec<Vec<Variable>>| {
+        expression
+            .iter()
+            .map(|variables| {
+                variables
+                    .iter()
+                    .map(|variable| {
+                        let position = inputs
+                            .iter()
+                            .position(|&input| input == variable.name)
+                            .unwrap();
+
+                        Variable::new(
+                            literal(inputs.len() - position - 1, variable.is_negated),
+                            false,
+                        )
+                    })
+                    .collect()
+            })
+            .collect()
+    };
+
+    match solution {
+        Solution::One => Solution::One,
+        Solution::Zero => Solution::Zero,
+        Solution::SOP(expression) => Solution::SOP(replace(expression)),
+        Solution::POS(expression) => Solution::POS(replace(expression)),
+    }
+}
+
+fn rust_style() -> Style {
+    Style {
+        one: "true".into(),
+        zero: "false".into(),
+        ..c_style()
+    }
+}
+
+fn c_style() -> Style {
+    Style {
+        and: "&&".into(),
+        or: "||".into(),
+        negation: Negation::Prefix("!".into()),
+        parentheses: Parentheses::MultipleTerms,
+        spaced: true,
+        one: "1".into(),
+        zero: "0".into(),
+    }
+}
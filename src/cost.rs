@@ -0,0 +1,76 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Form, Solution};
+
+/// How the cost of a [`Solution`] is measured, see [`Solution::cost`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum CostMetric {
+    /// The number of variable occurrences.
+    Literals,
+    /// The number of product terms in SOP form or sum terms in POS form.
+    Terms,
+    /// The number of gate inputs of the two-level circuit, not counting inverters:
+    /// the literals of the terms with more than one literal plus the terms if there is more than one.
+    GateInputs,
+}
+
+/// The solutions of a function in both forms, see [`minimize_both`](crate::minimize_both).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct BothForms {
+    pub sop: Vec<Solution>,
+    pub pos: Vec<Solution>,
+}
+
+impl BothForms {
+    /// Returns the costs of the first SOP and the first POS solution.
+    pub fn costs(&self, metric: CostMetric) -> (usize, usize) {
+        (self.sop[0].cost(metric), self.pos[0].cost(metric))
+    }
+
+    /// Returns the form whose first solution is cheaper, [`SOP`](Form::SOP) if they cost the same.
+    pub fn cheaper_form(&self, metric: CostMetric) -> Form {
+        let (sop, pos) = self.costs(metric);
+
+        if pos < sop {
+            Form::POS
+        } else {
+            Form::SOP
+        }
+    }
+
+    /// Returns the first solution of the cheaper form, see [`BothForms::cheaper_form`].
+    pub fn cheaper(&self, metric: CostMetric) -> &Solution {
+        match self.cheaper_form(metric) {
+            Form::SOP => &self.sop[0],
+            Form::POS => &self.pos[0],
+        }
+    }
+}
+
+pub(crate) fn cost(solution: &Solution, metric: CostMetric) -> usize {
+    let expression = match solution.expression() {
+        Some((expression, _)) => expression,
+        None => return 0,
+    };
+
+    match metric {
+        CostMetric::Literals => solution.literal_count(),
+        CostMetric::Terms => expression.len(),
+        CostMetric::GateInputs => {
+            let term_inputs = expression
+                .iter()
+                .map(Vec::len)
+                .filter(|&len| len > 1)
+                .sum::<usize>();
+
+            if expression.len() > 1 {
+                term_inputs + expression.len()
+            } else {
+                term_inputs
+            }
+        }
+    }
+}
@@ -0,0 +1,290 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::implicant::{Implicant, VariableSort};
+use crate::reed_muller::expansion;
+use crate::{own_variables, validate_input, Error, Form, Variable};
+
+/// Fully specified functions of up to this many variables also get their Reed–Muller expansion
+/// as a starting point of the heuristic.
+const MAX_TRANSFORM_VARIABLE_COUNT: usize = 20;
+
+/// An exclusive-or of products, see [`minimize_esop`](crate::minimize_esop).
+///
+/// Its value is 1 for a term if an odd number of its products are 1 for the term.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Esop {
+    variables: Vec<String>,
+    cubes: Vec<Implicant>,
+}
+
+impl Esop {
+    pub(crate) fn new(variables: Vec<String>, mut cubes: Vec<Implicant>) -> Self {
+        cubes.variable_sort(Form::SOP);
+
+        Esop { variables, cubes }
+    }
+
+    /// Returns the products, each as the variables it is the conjunction of.
+    ///
+    /// An empty product is the constant 1 and an expression without products is the constant 0.
+    pub fn products(&self) -> Vec<Vec<Variable>> {
+        self.cubes
+            .iter()
+            .map(|cube| cube.to_variables(&self.variables, Form::SOP))
+            .collect()
+    }
+
+    pub fn product_count(&self) -> usize {
+        self.cubes.len()
+    }
+
+    /// Returns the number of variable occurrences in the expression.
+    pub fn literal_count(&self) -> usize {
+        self.cubes
+            .iter()
+            .map(|cube| self.variables.len() - cube.wildcard_count() as usize)
+            .sum()
+    }
+
+    /// Evaluates the expression for the given term, the first variable being the most significant bit.
+    pub fn evaluate(&self, term: u32) -> bool {
+        self.cubes.iter().filter(|cube| cube.contains(term)).count() % 2 == 1
+    }
+}
+
+impl Display for Esop {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let products = self.products();
+
+        if products.is_empty() {
+            return write!(f, "0");
+        }
+
+        let products = products
+            .iter()
+            .map(|variables| {
+                let product = variables
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ∧ ");
+
+                if product.is_empty() {
+                    "1".to_owned()
+                } else if variables.len() > 1 && self.cubes.len() > 1 {
+                    format!("({})", product)
+                } else {
+                    product
+                }
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", products.join(" ⊕ "))
+    }
+}
+
+/// Minimizes the boolean function represented by the given `minterms` and `dont_cares`
+/// into an exclusive-or of products (ESOP).
+///
+/// Functions like parity checks and adders, which need exponentially many products in SOP form,
+/// often have a much smaller ESOP.
+///
+/// Exact ESOP minimization is intractable beyond a few variables, so this is a heuristic:
+/// starting from the minterms and the Reed–Muller expansion of both the completion of the don't cares
+/// with 0 and with 1, pairs of products are merged and reshaped with the EXORLINK operation
+/// as long as the number of products decreases. The smallest result is returned,
+/// which is not guaranteed to be minimal.
+///
+/// Returns an [`Error`] for the same bad input as [`minimize_minterms`](crate::minimize_minterms).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// // Odd parity of 4 variables, which needs 8 products in SOP form.
+/// let minterms = [1, 2, 4, 7, 8, 11, 13, 14];
+/// let esop = qmc::minimize_esop(&qmc::DEFAULT_VARIABLES[..4], &minterms, &[]).unwrap();
+///
+/// assert_eq!(esop.to_string(), "A ⊕ B ⊕ C ⊕ D");
+/// assert!((0..16).all(|term| esop.evaluate(term) == minterms.contains(&term)));
+/// ```
+pub fn minimize_esop<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    dont_cares: &[u32],
+) -> Result<Esop, Error> {
+    let variables = own_variables(variables);
+    let minterms = minterms.iter().copied().collect::<HashSet<_>>();
+    let dont_cares = dont_cares.iter().copied().collect::<HashSet<_>>();
+
+    validate_input(&variables, &minterms, &dont_cares)?;
+
+    let variable_count = variables.len();
+    let mut completions = vec![minterms.clone()];
+
+    if !dont_cares.is_empty() {
+        completions.push(minterms.union(&dont_cares).copied().collect());
+    }
+
+    let mut best: Option<Vec<Implicant>> = None;
+
+    for ones in completions {
+        let mut starts = vec![ones.iter().copied().map(Implicant::new).collect()];
+
+        if variable_count <= MAX_TRANSFORM_VARIABLE_COUNT {
+            starts.push(expansion(variable_count, |term| ones.contains(&term), 0));
+        }
+
+        for start in starts {
+            let cubes = improve(start);
+
+            if best.as_ref().map_or(true, |best| {
+                cost(variable_count, &cubes) < cost(variable_count, best)
+            }) {
+                best = Some(cubes);
+            }
+        }
+    }
+
+    Ok(Esop::new(variables, best.unwrap()))
+}
+
+fn cost(variable_count: usize, cubes: &[Implicant]) -> (usize, usize) {
+    let literal_count = cubes
+        .iter()
+        .map(|cube| variable_count - cube.wildcard_count() as usize)
+        .sum();
+
+    (cubes.len(), literal_count)
+}
+
+/// Repeatedly merges products and reshapes pairs of them to enable merges,
+/// until the number of products can't be decreased.
+fn improve(mut cubes: Vec<Implicant>) -> Vec<Implicant> {
+    loop {
+        merge_all(&mut cubes);
+
+        if !exorlink(&mut cubes) {
+            return cubes;
+        }
+    }
+}
+
+/// Merges pairs of products at distance 0 (removing both) or 1 (replacing them with one) until there are none.
+fn merge_all(cubes: &mut Vec<Implicant>) {
+    let mut i = 0;
+
+    while i < cubes.len() {
+        let mut merged = false;
+
+        for j in i + 1..cubes.len() {
+            match distance(cubes[i], cubes[j]) {
+                0 => {
+                    cubes.swap_remove(j);
+                    cubes.swap_remove(i);
+                }
+                1 => {
+                    let cube = merge(cubes[i], cubes[j]);
+                    cubes.swap_remove(j);
+                    cubes[i] = cube;
+                }
+                _ => continue,
+            }
+
+            merged = true;
+            break;
+        }
+
+        if merged {
+            // The new product may merge with the ones before it.
+            i = 0;
+        } else {
+            i += 1;
+        }
+    }
+}
+
+/// Replaces a pair of products at distance 2 with an equivalent pair
+/// if one of the new products can then be merged with another product. Returns whether it did.
+fn exorlink(cubes: &mut [Implicant]) -> bool {
+    for i in 0..cubes.len() {
+        for j in i + 1..cubes.len() {
+            if distance(cubes[i], cubes[j]) != 2 {
+                continue;
+            }
+
+            for (first, second) in exorlinks(cubes[i], cubes[j]) {
+                let enables_merge = cubes.iter().enumerate().any(|(k, &cube)| {
+                    k != i && k != j && (distance(cube, first) <= 1 || distance(cube, second) <= 1)
+                }) || distance(first, second) <= 1;
+
+                if enables_merge {
+                    cubes[i] = first;
+                    cubes[j] = second;
+                    return true;
+                }
+            }
+        }
+    }
+
+    false
+}
+
+/// Returns the positions of the variables where the literals of the products differ.
+fn difference(a: Implicant, b: Implicant) -> u32 {
+    (a.mask() ^ b.mask()) | (a.value() ^ b.value())
+}
+
+fn distance(a: Implicant, b: Implicant) -> u32 {
+    difference(a, b).count_ones()
+}
+
+/// Returns the literal at the positions of `bits` that is the exclusive-or of the different literals of the products
+/// at those positions, e.g. `A ⊕ ~A = 1` and `A ⊕ 1 = ~A`.
+fn xor_literals(a: Implicant, b: Implicant, bits: u32) -> (u32, u32) {
+    let both_fixed = bits & !a.mask() & !b.mask();
+    // Where one is a wildcard, the result is the complement of the other's literal.
+    let one_fixed = bits & !both_fixed;
+    let value = ((a.value() | b.value()) & one_fixed) ^ one_fixed;
+
+    (value, both_fixed)
+}
+
+/// Merges two products at distance 1 into one.
+fn merge(a: Implicant, b: Implicant) -> Implicant {
+    replace(a, difference(a, b), xor_literals(a, b, difference(a, b)))
+}
+
+/// Replaces the literals of the product at the positions of `bits`.
+fn replace(cube: Implicant, bits: u32, (value, mask): (u32, u32)) -> Implicant {
+    Implicant::from_parts(cube.value() & !bits | value, cube.mask() & !bits | mask)
+}
+
+/// Returns the two pairs of products equivalent to a pair at distance 2.
+///
+/// With `x` and `y` being the differing positions, `a ⊕ b = (aₓ ⊕ bₓ)a_y ⊕ bₓ(a_y ⊕ b_y)`
+/// and symmetrically with `x` and `y` swapped.
+fn exorlinks(a: Implicant, b: Implicant) -> [(Implicant, Implicant); 2] {
+    let diff = difference(a, b);
+    let x = 1 << diff.trailing_zeros();
+    let y = diff & !x;
+
+    let link = |x: u32, y: u32| {
+        let first = replace(a, x, xor_literals(a, b, x));
+        let second = replace(
+            replace(a, x, (b.value() & x, b.mask() & x)),
+            y,
+            xor_literals(a, b, y),
+        );
+
+        (first, second)
+    };
+
+    [link(x, y), link(y, x)]
+}
@@ -0,0 +1,344 @@
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Form, Parentheses, Solution, Style, Variable};
+
+/// A multi-level boolean expression, see [`Solution::factor`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Expression {
+    One,
+    Zero,
+    Variable(Variable),
+    And(Vec<Expression>),
+    Or(Vec<Expression>),
+}
+
+/// A set of literal indices, a product term in SOP form and a sum term in POS form.
+type Cube = BTreeSet<usize>;
+
+impl Expression {
+    /// Returns the number of variable occurrences in the expression.
+    pub fn literal_count(&self) -> usize {
+        match self {
+            Expression::One | Expression::Zero => 0,
+            Expression::Variable(_) => 1,
+            Expression::And(operands) | Expression::Or(operands) => {
+                operands.iter().map(Expression::literal_count).sum()
+            }
+        }
+    }
+
+    /// Evaluates the expression for the given term of the `variables`, the first variable being the most significant bit.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the expression contains a variable that is not in `variables`.
+    pub fn evaluate<T: AsRef<str>>(&self, variables: &[T], term: u32) -> bool {
+        match self {
+            Expression::One => true,
+            Expression::Zero => false,
+            Expression::Variable(variable) => {
+                let position = variables
+                    .iter()
+                    .position(|name| name.as_ref() == variable.name)
+                    .unwrap_or_else(|| panic!("Unknown variable: {}", variable.name));
+
+                (term >> (variables.len() - position - 1) & 1 == 1) != variable.is_negated
+            }
+            Expression::And(operands) => operands
+                .iter()
+                .all(|operand| operand.evaluate(variables, term)),
+            Expression::Or(operands) => operands
+                .iter()
+                .any(|operand| operand.evaluate(variables, term)),
+        }
+    }
+
+    /// Formats the expression with the given [`Style`].
+    ///
+    /// Nested operations are put in parentheses if they have more than one operand,
+    /// or with [`Parentheses::Precedence`], only if they are disjunctions inside a conjunction.
+    pub fn format(&self, style: &Style) -> String {
+        self.format_nested(style, None)
+    }
+
+    fn format_nested(&self, style: &Style, parent: Option<Form>) -> String {
+        let (operands, operator, form) = match self {
+            Expression::One => return style.one.clone(),
+            Expression::Zero => return style.zero.clone(),
+            Expression::Variable(variable) => return style.format_variable(variable),
+            Expression::And(operands) => (operands, style.outer_operator(Form::POS), Form::POS),
+            Expression::Or(operands) => (operands, style.outer_operator(Form::SOP), Form::SOP),
+        };
+
+        let formatted = operands
+            .iter()
+            .map(|operand| operand.format_nested(style, Some(form)))
+            .collect::<Vec<_>>()
+            .join(&operator);
+
+        let needs_parentheses = match parent {
+            None => false,
+            Some(_) if operands.len() < 2 => false,
+            Some(parent) => style.parentheses != Parentheses::Precedence || parent == Form::POS,
+        };
+
+        if needs_parentheses {
+            format!("({})", formatted)
+        } else {
+            formatted
+        }
+    }
+
+    fn and(operands: Vec<Expression>) -> Expression {
+        Expression::flatten(operands, true)
+    }
+
+    fn or(operands: Vec<Expression>) -> Expression {
+        Expression::flatten(operands, false)
+    }
+
+    fn flatten(operands: Vec<Expression>, is_and: bool) -> Expression {
+        let mut flattened = vec![];
+
+        for operand in operands {
+            match operand {
+                Expression::And(nested) if is_and => flattened.extend(nested),
+                Expression::Or(nested) if !is_and => flattened.extend(nested),
+                _ => flattened.push(operand),
+            }
+        }
+
+        if flattened.len() == 1 {
+            flattened.pop().unwrap()
+        } else if is_and {
+            Expression::And(flattened)
+        } else {
+            Expression::Or(flattened)
+        }
+    }
+
+    /// Swaps the conjunctions and disjunctions, turning a factored SOP into the factored POS of its dual.
+    fn dual(self) -> Expression {
+        match self {
+            Expression::And(operands) => {
+                Expression::Or(operands.into_iter().map(Expression::dual).collect())
+            }
+            Expression::Or(operands) => {
+                Expression::And(operands.into_iter().map(Expression::dual).collect())
+            }
+            Expression::One => Expression::Zero,
+            Expression::Zero => Expression::One,
+            variable => variable,
+        }
+    }
+}
+
+impl Display for Expression {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(&Style::unicode()))
+    }
+}
+
+/// Factors the solution with algebraic division by its kernels.
+///
+/// A [`Solution::POS`] is factored as its dual [`Solution::SOP`] and the result is dualized back.
+pub(crate) fn factor(solution: &Solution) -> Expression {
+    let (expression, form) = match solution.expression() {
+        Some(expression) => expression,
+        None if matches!(solution, Solution::One) => return Expression::One,
+        None => return Expression::Zero,
+    };
+
+    let mut literals: Vec<Variable> = vec![];
+    let mut cubes = vec![];
+
+    for variables in expression {
+        let mut cube = Cube::new();
+
+        for variable in variables {
+            let index = match literals.iter().position(|literal| {
+                literal.name == variable.name && literal.is_negated == variable.is_negated
+            }) {
+                Some(index) => index,
+                None => {
+                    literals.push(variable.clone());
+                    literals.len() - 1
+                }
+            };
+
+            cube.insert(index);
+        }
+
+        cubes.push(cube);
+    }
+
+    let factored = factor_cubes(cubes, &literals);
+
+    if form == Form::SOP {
+        factored
+    } else {
+        factored.dual()
+    }
+}
+
+fn factor_cubes(mut cubes: Vec<Cube>, literals: &[Variable]) -> Expression {
+    cubes.sort_unstable();
+    cubes.dedup();
+
+    let literal = |index: usize| Expression::Variable(literals[index].clone());
+    let product = |cube: &Cube| {
+        if cube.is_empty() {
+            Expression::One
+        } else {
+            Expression::and(cube.iter().map(|&index| literal(index)).collect())
+        }
+    };
+
+    if cubes.iter().any(Cube::is_empty) {
+        return Expression::One;
+    }
+
+    if cubes.len() == 1 {
+        return product(&cubes[0]);
+    }
+
+    let common = common_cube(&cubes);
+
+    if !common.is_empty() {
+        let quotient = cubes
+            .iter()
+            .map(|cube| cube.difference(&common).copied().collect())
+            .collect();
+
+        return Expression::and(vec![product(&common), factor_cubes(quotient, literals)]);
+    }
+
+    let mut kernels = vec![];
+    find_kernels(&cubes, 0, &mut kernels);
+
+    let literal_count = |cubes: &[Cube]| cubes.iter().map(Cube::len).sum::<usize>();
+    let best = kernels
+        .into_iter()
+        .filter(|kernel| *kernel != cubes)
+        .map(|kernel| {
+            let (quotient, remainder) = divide(&cubes, &kernel);
+            let cost =
+                literal_count(&quotient) + literal_count(&kernel) + literal_count(&remainder);
+
+            (cost, quotient, kernel, remainder)
+        })
+        .filter(|(_, quotient, _, _)| !quotient.is_empty())
+        .min_by_key(|(cost, _, _, _)| *cost);
+
+    match best {
+        Some((cost, quotient, kernel, remainder)) if cost < literal_count(&cubes) => {
+            let mut operands = vec![Expression::and(vec![
+                factor_cubes(quotient, literals),
+                factor_cubes(kernel, literals),
+            ])];
+
+            if !remainder.is_empty() {
+                operands.push(factor_cubes(remainder, literals));
+            }
+
+            Expression::or(operands)
+        }
+        _ => Expression::or(cubes.iter().map(product).collect()),
+    }
+}
+
+/// Returns the literals common to all cubes.
+fn common_cube<'a>(cubes: impl IntoIterator<Item = &'a Cube>) -> Cube {
+    let mut cubes = cubes.into_iter();
+    let mut common = cubes.next().cloned().unwrap_or_default();
+
+    for cube in cubes {
+        common.retain(|index| cube.contains(index));
+    }
+
+    common
+}
+
+/// Collects the kernels, the cube-free quotients of the cubes divided by a cube,
+/// considering only the literals from `start` on to avoid finding the same kernel more than once.
+fn find_kernels(cubes: &[Cube], start: usize, kernels: &mut Vec<Vec<Cube>>) {
+    let literal_end = cubes
+        .iter()
+        .filter_map(|cube| cube.iter().next_back())
+        .max()
+        .map_or(0, |&index| index + 1);
+
+    for index in start..literal_end {
+        let containing = cubes
+            .iter()
+            .filter(|cube| cube.contains(&index))
+            .collect::<Vec<_>>();
+
+        if containing.len() < 2 {
+            continue;
+        }
+
+        let common = common_cube(containing.iter().copied());
+
+        if common.iter().any(|&other| other < index) {
+            continue;
+        }
+
+        let mut quotient = containing
+            .iter()
+            .map(|cube| cube.difference(&common).copied().collect::<Cube>())
+            .collect::<Vec<_>>();
+        quotient.sort_unstable();
+
+        find_kernels(&quotient, index + 1, kernels);
+    }
+
+    if cubes.len() > 1 && common_cube(cubes).is_empty() {
+        let mut kernel = cubes.to_vec();
+        kernel.sort_unstable();
+
+        if !kernels.contains(&kernel) {
+            kernels.push(kernel);
+        }
+    }
+}
+
+/// Weak (algebraic) division of the cubes by the divisor, returning the quotient and the remainder.
+fn divide(cubes: &[Cube], divisor: &[Cube]) -> (Vec<Cube>, Vec<Cube>) {
+    let mut quotient: Option<BTreeSet<Cube>> = None;
+
+    for divisor_cube in divisor {
+        let partial = cubes
+            .iter()
+            .filter(|cube| divisor_cube.is_subset(cube))
+            .map(|cube| cube.difference(divisor_cube).copied().collect())
+            .collect::<BTreeSet<Cube>>();
+
+        quotient = Some(match quotient {
+            Some(quotient) => quotient.intersection(&partial).cloned().collect(),
+            None => partial,
+        });
+    }
+
+    let quotient = quotient.unwrap_or_default().into_iter().collect::<Vec<_>>();
+    let products = quotient
+        .iter()
+        .flat_map(|quotient_cube| {
+            divisor
+                .iter()
+                .map(move |divisor_cube| quotient_cube.union(divisor_cube).copied().collect())
+        })
+        .collect::<BTreeSet<Cube>>();
+    let remainder = cubes
+        .iter()
+        .filter(|cube| !products.contains(*cube))
+        .cloned()
+        .collect();
+
+    (quotient, remainder)
+}
@@ -66,6 +66,10 @@ impl Group {
             .collect()
     }
 
+    pub fn implicant_count(&self) -> usize {
+        self.implicants.len()
+    }
+
     pub fn was_combined(&self) -> bool {
         *self.was_combined.borrow()
     }
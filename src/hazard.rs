@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+use std::time::Duration;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::implicant::Implicant;
+use crate::{
+    get_dont_cares, minimize_internal_with_timeout, own_variables, validate_input, Error, Form,
+    Solution,
+};
+
+/// The input transitions a [hazard-free](minimize_hazard_free) solution has to be glitch-free for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Transitions {
+    /// Every change of a single variable between two minterms ([`SOP`](Form::SOP))
+    /// or two maxterms ([`POS`](Form::POS)).
+    SingleVariable,
+    /// The given pairs of terms, between which any number of variables may change.
+    Listed(Vec<(u32, u32)>),
+}
+
+/// Minimizes the boolean function represented by the given `minterms` and `maxterms`
+/// into an expression free of static hazards for the given input `transitions`.
+///
+/// A static-1 hazard is a glitch to 0 of an [`SOP`](Form::SOP) expression while the inputs change
+/// between two minterms, caused by one product term turning off before another one turns on.
+/// It can't occur if a single product covers all terms the inputs may pass through,
+/// so each transition adds the cube spanned by its two terms as a requirement to the prime implicant chart.
+/// The same goes for static-0 hazards of [`POS`](Form::POS) expressions between maxterms.
+/// The result is the cheapest solution meeting the requirements, which may have more terms than [`minimize`](crate::minimize)'s.
+///
+/// Returns [`Error::InvalidTransition`] if a transition isn't between two minterms ([`SOP`](Form::SOP))
+/// or maxterms ([`POS`](Form::POS)), or passes through a term of the other kind, since the function itself
+/// changes during such a transition. Otherwise returns an [`Error`] for the same reasons as [`minimize`](crate::minimize).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["sel", "a", "b"];
+/// let (minterms, maxterms) = ([2, 3, 5, 7], [0, 1, 4, 6]);
+///
+/// let solution = qmc::minimize(&variables, &minterms, &maxterms, qmc::SOP, false, None).unwrap();
+/// let hazard_free = qmc::minimize_hazard_free(
+///     &variables,
+///     &minterms,
+///     &maxterms,
+///     qmc::SOP,
+///     &qmc::Transitions::SingleVariable,
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// // Switching sel with a = b = 1 (terms 3 and 7) glitches without the consensus term a ∧ b.
+/// assert_eq!(solution[0].to_string(), "(sel ∧ b) ∨ (~sel ∧ a)");
+/// assert_eq!(hazard_free[0].to_string(), "(sel ∧ b) ∨ (~sel ∧ a) ∨ (a ∧ b)");
+/// ```
+pub fn minimize_hazard_free<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: Form,
+    transitions: &Transitions,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<Vec<Solution>, Error> {
+    let variables = own_variables(variables);
+
+    let variable_count = variables.len();
+    let variable_count =
+        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
+
+    let minterms = minterms.iter().copied().collect::<HashSet<_>>();
+    let maxterms = maxterms.iter().copied().collect::<HashSet<_>>();
+
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let (terms, other_terms) = if form == Form::SOP {
+        (minterms, maxterms)
+    } else {
+        (maxterms, minterms)
+    };
+
+    let required_cubes = match transitions {
+        Transitions::SingleVariable => {
+            let mut cubes = vec![];
+
+            for &term in &terms {
+                for bit in 0..variable_count {
+                    let neighbor = term ^ 1 << bit;
+
+                    if term < neighbor && terms.contains(&neighbor) {
+                        cubes.push(Implicant::from_parts(term, 1 << bit));
+                    }
+                }
+            }
+
+            cubes
+        }
+        Transitions::Listed(transitions) => {
+            let mut cubes = vec![];
+
+            for &(from, to) in transitions {
+                let cube = Implicant::from_parts(from, from ^ to);
+
+                if !terms.contains(&from)
+                    || !terms.contains(&to)
+                    || other_terms.iter().any(|&term| cube.contains(term))
+                {
+                    return Err(Error::InvalidTransition(from, to));
+                }
+
+                cubes.push(cube);
+            }
+
+            cubes
+        }
+    };
+
+    let internal_solutions = minimize_internal_with_timeout(
+        variable_count,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        required_cubes,
+        timeout,
+    )?;
+
+    Ok(internal_solutions
+        .iter()
+        .map(|solution| Solution::new(solution, &variables, form))
+        .collect())
+}
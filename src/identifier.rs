@@ -0,0 +1,146 @@
+use std::collections::HashSet;
+
+use crate::{Error, Solution};
+
+/// The identifier rules of a target language.
+pub struct IdentifierRules {
+    pub keywords: &'static [&'static str],
+    pub is_case_insensitive: bool,
+    /// Identifiers must start with a letter and can't have leading, trailing or consecutive underscores.
+    pub has_strict_underscores: bool,
+}
+
+impl IdentifierRules {
+    pub fn is_valid(&self, name: &str) -> bool {
+        let mut chars = name.chars();
+
+        match chars.next() {
+            Some(char) if char.is_ascii_alphabetic() => {}
+            Some('_') if !self.has_strict_underscores => {}
+            _ => return false,
+        }
+
+        if self.has_strict_underscores && (name.ends_with('_') || name.contains("__")) {
+            return false;
+        }
+
+        chars.all(|char| char.is_ascii_alphanumeric() || char == '_') && !self.is_keyword(name)
+    }
+
+    pub fn sanitize(&self, name: &str) -> String {
+        let mut sanitized: String = name
+            .chars()
+            .map(|char| {
+                if char.is_ascii_alphanumeric() {
+                    char
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+
+        if self.has_strict_underscores {
+            sanitized = sanitized
+                .split('_')
+                .filter(|part| !part.is_empty())
+                .collect::<Vec<_>>()
+                .join("_");
+
+            if !sanitized.starts_with(|char: char| char.is_ascii_alphabetic())
+                || self.is_keyword(&sanitized)
+            {
+                sanitized.insert_str(0, if sanitized.is_empty() { "x" } else { "x_" });
+            }
+        } else {
+            if !sanitized.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_') {
+                sanitized.insert(0, '_');
+            }
+
+            if self.is_keyword(&sanitized) {
+                sanitized.push('_');
+            }
+        }
+
+        sanitized
+    }
+
+    fn is_keyword(&self, name: &str) -> bool {
+        if self.is_case_insensitive {
+            self.keywords
+                .iter()
+                .any(|keyword| keyword.eq_ignore_ascii_case(name))
+        } else {
+            self.keywords.contains(&name)
+        }
+    }
+}
+
+/// Hands out sanitized identifiers that are unique within the namespace.
+pub struct Namespace<'a> {
+    rules: &'a IdentifierRules,
+    used: HashSet<String>,
+}
+
+impl<'a> Namespace<'a> {
+    pub fn new(rules: &'a IdentifierRules) -> Self {
+        Namespace {
+            rules,
+            used: HashSet::new(),
+        }
+    }
+
+    /// Reserves the sanitized `name`, appending a numeric suffix if it is already taken.
+    pub fn insert(&mut self, name: &str) -> String {
+        let sanitized = self.rules.sanitize(name);
+        let mut identifier = sanitized.clone();
+        let mut suffix = 1;
+
+        while !self.reserve(&identifier) {
+            identifier = format!("{}_{}", sanitized, suffix);
+            suffix += 1;
+        }
+
+        identifier
+    }
+
+    /// Reserves `identifier` as is. Returns `false` if it is already taken.
+    pub fn reserve(&mut self, identifier: &str) -> bool {
+        if self.rules.is_case_insensitive {
+            self.used.insert(identifier.to_ascii_lowercase())
+        } else {
+            self.used.insert(identifier.to_owned())
+        }
+    }
+}
+
+/// Checks that the port names are unique and that the solutions only use the inputs.
+pub fn validate_ports<'a>(
+    inputs: &[&str],
+    outputs: &[&str],
+    solutions: impl Iterator<Item = &'a Solution>,
+) -> Result<(), Error> {
+    let mut names = HashSet::new();
+    let mut duplicates = HashSet::new();
+
+    for &name in inputs.iter().chain(outputs) {
+        if !names.insert(name) {
+            duplicates.insert(name.to_owned());
+        }
+    }
+
+    if !duplicates.is_empty() {
+        return Err(Error::DuplicateVariables(duplicates));
+    }
+
+    for solution in solutions {
+        if let Some((expression, _)) = solution.expression() {
+            for variable in expression.iter().flatten() {
+                if !inputs.contains(&variable.name.as_str()) {
+                    return Err(Error::UnknownVariable(variable.name.clone()));
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -2,9 +2,13 @@
 
 use std::{cmp::Ordering, collections::HashSet, hash::Hash};
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::{solution::Variable, Form};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct Implicant {
     value: u32,
     mask: u32,
@@ -18,6 +22,31 @@ impl Implicant {
         }
     }
 
+    /// Creates an implicant from the bits of its literals and its mask of wildcards.
+    pub fn from_parts(value: u32, mask: u32) -> Self {
+        Implicant {
+            value: value & !mask,
+            mask,
+        }
+    }
+
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    pub fn mask(&self) -> u32 {
+        self.mask
+    }
+
+    pub fn contains(&self, term: u32) -> bool {
+        term & !self.mask == self.value
+    }
+
+    /// Returns whether all terms of the other implicant are terms of this one.
+    pub fn covers(&self, other: Implicant) -> bool {
+        other.mask & !self.mask == 0 && other.value & !self.mask == self.value
+    }
+
     pub fn combine(&self, other: Self) -> Option<Self> {
         if self.mask == other.mask {
             let diff = self.value ^ other.value;
@@ -60,6 +89,23 @@ impl Implicant {
         self.mask.count_ones()
     }
 
+    pub fn to_str(self, variable_count: u32) -> String {
+        let mut str = String::new();
+
+        for i in (0..variable_count).rev() {
+            let value_bit = (self.value >> i) & 1;
+            let mask_bit = (self.mask >> i) & 1;
+
+            if mask_bit == 1 {
+                str.push('-');
+            } else {
+                str.push(if value_bit == 1 { '1' } else { '0' });
+            }
+        }
+
+        str
+    }
+
     pub fn to_variables(self, variable_names: &[String], form: Form) -> Vec<Variable> {
         let mut variables = vec![];
         let variable_count = variable_names.len();
@@ -136,22 +182,5 @@ mod tests {
                 mask: u32::from_str_radix(&str.replace('1', "0").replace('-', "1"), 2).unwrap(),
             }
         }
-
-        pub fn to_str(self, variable_count: u32) -> String {
-            let mut str = String::new();
-
-            for i in (0..variable_count).rev() {
-                let value_bit = (self.value >> i) & 1;
-                let mask_bit = (self.mask >> i) & 1;
-
-                if mask_bit == 1 {
-                    str.push('-');
-                } else {
-                    str.push(if value_bit == 1 { '1' } else { '0' });
-                }
-            }
-
-            str
-        }
     }
 }
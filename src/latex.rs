@@ -0,0 +1,184 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Form, Negation, Parentheses, Solution, Style};
+
+/// LaTeX rendering options for [`Solution`]s and their specifications.
+///
+/// The rendered strings are meant to be used in math mode.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solution = qmc::minimize_minterms(&qmc::DEFAULT_VARIABLES[..3], &[0, 5], &[2, 7], false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// let latex = qmc::Latex::default();
+///
+/// assert_eq!(latex.expression(&solution), "AC + \\overline{A}\\overline{C}");
+/// assert_eq!(
+///     qmc::Latex::specification(qmc::SOP, &[0, 5], &[2, 7]),
+///     "\\sum m(0,5) + d(2,7)"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Latex {
+    pub notation: LatexNotation,
+    /// If set, [`Latex::equation`] breaks expressions with more terms than this
+    /// into multiple lines of an `align*` environment.
+    pub terms_per_line: Option<usize>,
+}
+
+/// The notation of the boolean operators in LaTeX.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum LatexNotation {
+    /// `\overline{A}B + C`
+    Overline,
+    /// `(\lnot A \land B) \lor C`
+    Logic,
+}
+
+impl Default for LatexNotation {
+    fn default() -> Self {
+        LatexNotation::Overline
+    }
+}
+
+impl Latex {
+    /// Renders the expression, e.g. `\overline{A}B + C`.
+    pub fn expression(&self, solution: &Solution) -> String {
+        self.style()
+            .format_solution(&solution.rename_variables(escape_name))
+    }
+
+    /// Renders the equation `name = expression`.
+    ///
+    /// If [`Latex::terms_per_line`] is set and the expression has more terms,
+    /// the equation is broken into multiple lines of an `align*` environment.
+    pub fn equation(&self, name: &str, solution: &Solution) -> String {
+        let name = escape_name(name);
+        let solution = solution.rename_variables(escape_name);
+
+        let (expression, form) = match (&solution, self.terms_per_line) {
+            (Solution::SOP(expression), Some(terms_per_line))
+                if expression.len() > terms_per_line.max(1) =>
+            {
+                (expression, Form::SOP)
+            }
+            (Solution::POS(expression), Some(terms_per_line))
+                if expression.len() > terms_per_line.max(1) =>
+            {
+                (expression, Form::POS)
+            }
+            _ => return format!("{} = {}", name, self.style().format_solution(&solution)),
+        };
+
+        let style = self.style();
+        let operator = style.outer_operator(form);
+        let lines = expression
+            .chunks(self.terms_per_line.unwrap().max(1))
+            .map(|terms| {
+                terms
+                    .iter()
+                    .map(|variables| style.format_term(variables, form, expression.len()))
+                    .collect::<Vec<_>>()
+                    .join(&operator)
+            })
+            .collect::<Vec<_>>();
+
+        let mut latex = format!("\\begin{{align*}}\n{} &= {}", name, lines[0]);
+
+        for line in &lines[1..] {
+            latex.push_str(&format!(" \\\\\n&\\quad {}{}", operator.trim_start(), line));
+        }
+
+        latex.push_str("\n\\end{align*}");
+
+        latex
+    }
+
+    /// Renders the specification of a boolean function as a sum of minterms
+    /// (`\sum m(0,5) + d(2,7)`) or a product of maxterms (`\prod M(1,3,4,6) \cdot D(2,7)`).
+    ///
+    /// `terms` are the minterms for [`SOP`](Form::SOP) and the maxterms for [`POS`](Form::POS).
+    pub fn specification(form: Form, terms: &[u32], dont_cares: &[u32]) -> String {
+        let list = |terms: &[u32]| {
+            let mut terms = terms.to_vec();
+            terms.sort_unstable();
+            terms.dedup();
+            terms
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(",")
+        };
+
+        let mut latex = if form == Form::SOP {
+            format!("\\sum m({})", list(terms))
+        } else {
+            format!("\\prod M({})", list(terms))
+        };
+
+        if !dont_cares.is_empty() {
+            if form == Form::SOP {
+                latex.push_str(&format!(" + d({})", list(dont_cares)));
+            } else {
+                latex.push_str(&format!(" \\cdot D({})", list(dont_cares)));
+            }
+        }
+
+        latex
+    }
+
+    fn style(&self) -> Style {
+        match self.notation {
+            LatexNotation::Overline => Style {
+                and: "".into(),
+                or: "+".into(),
+                negation: Negation::Enclose("\\overline{".into(), "}".into()),
+                parentheses: Parentheses::Precedence,
+                spaced: true,
+                one: "1".into(),
+                zero: "0".into(),
+            },
+            LatexNotation::Logic => Style {
+                and: "\\land".into(),
+                or: "\\lor".into(),
+                negation: Negation::Prefix("\\lnot ".into()),
+                parentheses: Parentheses::MultipleTerms,
+                spaced: true,
+                one: "1".into(),
+                zero: "0".into(),
+            },
+        }
+    }
+}
+
+fn escape_name(name: &str) -> String {
+    let mut escaped = String::new();
+
+    for char in name.chars() {
+        match char {
+            '#' | '$' | '%' | '&' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(char);
+            }
+            '^' => escaped.push_str("\\hat{}"),
+            '~' => escaped.push_str("\\sim{}"),
+            '\\' => escaped.push_str("\\backslash{}"),
+            _ => escaped.push(char),
+        }
+    }
+
+    if name.chars().count() > 1 {
+        format!("\\mathit{{{}}}", escaped)
+    } else {
+        escaped
+    }
+}
@@ -23,30 +23,76 @@
 //!
 //! [`minimize`] is sufficient for all use cases. But also check [`minimize_minterms`] and
 //! [`minimize_maxterms`] to see if they are more suitable for your use case.
+//! They all delegate to [`Minimizer`], which takes the same options as a builder
+//! and can solve the function again after editing single terms.
 //!
 //! # Feature flags
 //!
 //! * `serde` -- Derives the [`Serialize`] and [`Deserialize`] traits for structs and enums.
+//! * `build` -- Enables [`Build`], a build script helper generating Rust code from truth table, PLA and JSON files.
 
 #![deny(deprecated)]
 
+mod anytime;
+mod bdd;
+#[cfg(feature = "build")]
+mod build;
+mod chart_table;
+mod codegen;
+mod cost;
+mod esop;
+mod factor;
 mod group;
+mod hazard;
+mod identifier;
 mod implicant;
+mod latex;
+mod minimizer;
+mod multi_valued;
+mod netlist;
 mod petrick;
 mod prime_implicant_chart;
+mod progress;
+mod reed_muller;
 mod solution;
+mod style;
 mod timeout_signal;
-
+mod verify;
+mod verilog;
+mod vhdl;
+
+pub use anytime::AnytimeSolutions;
+pub use bdd::{Bdd, Operation};
+#[cfg(feature = "build")]
+pub use build::{Build, BuildError};
+pub use chart_table::{ChartColumn, ChartRow, ChartTable};
+pub use codegen::{CCode, Parameters, RustCode};
+pub use cost::{BothForms, CostMetric};
+pub use esop::{minimize_esop, Esop};
+pub use factor::Expression;
+pub use hazard::{minimize_hazard_free, Transitions};
+pub use latex::{Latex, LatexNotation};
+pub use minimizer::Minimizer;
+pub use multi_valued::{
+    minimize_multi_valued, MultiValuedSolution, MultiValuedVariable, SetLiteral, MAX_VALUE_COUNT,
+};
+pub use netlist::{Gate, GateKind, Inverters, Netlist, Signal};
+pub use progress::Progress;
+pub use reed_muller::{anf, minimize_reed_muller, reed_muller};
 pub use solution::Solution;
 pub use solution::Variable;
+pub use style::{Negation, Parentheses, Style};
+pub use timeout_signal::CancellationToken;
+pub use verify::{Counterexample, Verification};
+pub use verilog::Verilog;
+pub use vhdl::Vhdl;
 #[doc(hidden)]
 pub use Form::{POS, SOP};
 
 use std::collections::HashSet;
 use std::ops::Not;
-use std::sync::{mpsc, Arc};
 use std::thread;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -55,7 +101,8 @@ use crate::group::Group;
 use crate::implicant::{Implicant, VariableSort};
 use crate::petrick::Petrick;
 use crate::prime_implicant_chart::PrimeImplicantChart;
-use crate::timeout_signal::{TTimeoutSignal, TimeoutSignalAtomicBool, TimeoutSignalNoOp};
+use crate::progress::{ProgressNoOp, TProgress};
+use crate::timeout_signal::{TTimeoutSignal, TimeoutSignalDeadline, TimeoutSignalNoOp};
 
 /// Minimizes the boolean function represented by the given `minterms` and `maxterms`.
 ///
@@ -135,33 +182,13 @@ pub fn minimize<T: AsRef<str>>(
     find_all_solutions: bool,
     timeout: Option<Duration>,
 ) -> Result<Vec<Solution>, Error> {
-    let variables = own_variables(variables);
-
-    let variable_count = variables.len();
-    let variable_count =
-        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
-
-    let minterms = minterms.iter().copied().collect();
-    let maxterms = maxterms.iter().copied().collect();
-
-    validate_input(&variables, &minterms, &maxterms)?;
-
-    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
-    let terms = if form == SOP { minterms } else { maxterms };
-
-    let internal_solutions = minimize_internal_with_timeout(
-        variable_count,
-        terms,
-        dont_cares,
-        form,
-        find_all_solutions,
-        timeout,
-    )?;
+    let minimizer = Minimizer::new(variables)
+        .minterms(minterms)
+        .maxterms(maxterms)
+        .form(form)
+        .all_solutions(find_all_solutions);
 
-    Ok(internal_solutions
-        .iter()
-        .map(|solution| Solution::new(solution, &variables, form))
-        .collect())
+    solve_with_timeout(minimizer, timeout)
 }
 
 /// Minimizes the boolean function represented by the given `minterms` and `dont_cares`.
@@ -208,30 +235,12 @@ pub fn minimize_minterms<T: AsRef<str>>(
     find_all_solutions: bool,
     timeout: Option<Duration>,
 ) -> Result<Vec<Solution>, Error> {
-    let variables = own_variables(variables);
+    let minimizer = Minimizer::new(variables)
+        .minterms(minterms)
+        .dont_cares(dont_cares)
+        .all_solutions(find_all_solutions);
 
-    let variable_count = variables.len();
-    let variable_count =
-        u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
-
-    let minterms = minterms.iter().copied().collect();
-    let dont_cares = dont_cares.iter().copied().collect();
-
-    validate_input(&variables, &minterms, &dont_cares)?;
-
-    let internal_solutions = minimize_internal_with_timeout(
-        variable_count,
-        minterms,
-        dont_cares,
-        SOP,
-        find_all_solutions,
-        timeout,
-    )?;
-
-    Ok(internal_solutions
-        .iter()
-        .map(|solution| Solution::new(solution, &variables, SOP))
-        .collect())
+    solve_with_timeout(minimizer, timeout)
 }
 
 /// Minimizes the boolean function represented by the given `maxterms` and `dont_cares`.
@@ -278,30 +287,148 @@ pub fn minimize_maxterms<T: AsRef<str>>(
     find_all_solutions: bool,
     timeout: Option<Duration>,
 ) -> Result<Vec<Solution>, Error> {
+    let minimizer = Minimizer::new(variables)
+        .maxterms(maxterms)
+        .dont_cares(dont_cares)
+        .form(POS)
+        .all_solutions(find_all_solutions);
+
+    solve_with_timeout(minimizer, timeout)
+}
+
+/// Minimizes the boolean function represented by the given `minterms` and `maxterms` in both [`SOP`] and [`POS`] form.
+///
+/// The two minimizations run in parallel if a `timeout` is given, each of them being limited by it.
+/// Use [`BothForms::cheaper`] to pick the cheaper solution under a [`CostMetric`].
+///
+/// Returns an [`Error`] for the same reasons as [`minimize`].
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let both = qmc::minimize_both(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 1, 2, 3, 4],
+///     &[5, 6, 7],
+///     false,
+///     None,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(both.sop[0].to_string(), "~A ∨ (~B ∧ ~C)");
+/// assert_eq!(both.pos[0].to_string(), "(~A ∨ ~B) ∧ (~A ∨ ~C)");
+/// assert_eq!(both.costs(qmc::CostMetric::Literals), (3, 4));
+/// assert_eq!(both.cheaper(qmc::CostMetric::GateInputs).to_string(), "~A ∨ (~B ∧ ~C)");
+/// ```
+pub fn minimize_both<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+) -> Result<BothForms, Error> {
+    if timeout.is_none() {
+        return Ok(BothForms {
+            sop: minimize(variables, minterms, maxterms, SOP, find_all_solutions, None)?,
+            pos: minimize(variables, minterms, maxterms, POS, find_all_solutions, None)?,
+        });
+    }
+
+    let (owned_variables, owned_minterms, owned_maxterms) = (
+        own_variables(variables),
+        minterms.to_vec(),
+        maxterms.to_vec(),
+    );
+    let pos_thread = thread::spawn(move || {
+        minimize(
+            &owned_variables,
+            &owned_minterms,
+            &owned_maxterms,
+            POS,
+            find_all_solutions,
+            timeout,
+        )
+    });
+    let sop = minimize(
+        variables,
+        minterms,
+        maxterms,
+        SOP,
+        find_all_solutions,
+        timeout,
+    );
+
+    // Join before returning an error, so the POS minimization doesn't keep running in the background.
+    let pos = pos_thread.join().unwrap();
+
+    Ok(BothForms {
+        sop: sop?,
+        pos: pos?,
+    })
+}
+
+/// Builds the prime implicant chart of the boolean function represented by the given `minterms` and `maxterms`.
+///
+/// The returned [`ChartTable`] can be rendered as plain text, Markdown or HTML.
+/// It shows the prime implicants in cube notation as rows and the care terms as columns,
+/// and flags the essential prime implicants and the rows and columns removed based on dominance.
+///
+/// `form` determines whether the chart is built from the minterms ([`SOP`]) or the maxterms ([`POS`]).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let chart = qmc::prime_implicant_chart(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     qmc::SOP,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     chart.to_markdown(),
+///     "| Implicant | 0 | 5 |\n\
+///      |:-|:-:|:-:|\n\
+///      | **0-0** | X |  |\n\
+///      | **1-1** |  | X |\n"
+/// );
+/// ```
+pub fn prime_implicant_chart<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: Form,
+) -> Result<ChartTable, Error> {
     let variables = own_variables(variables);
 
     let variable_count = variables.len();
     let variable_count =
         u32::try_from(variable_count).map_err(|_| Error::InvalidVariableCount(variable_count))?;
 
+    let minterms = minterms.iter().copied().collect();
     let maxterms = maxterms.iter().copied().collect();
-    let dont_cares = dont_cares.iter().copied().collect();
 
-    validate_input(&variables, &maxterms, &dont_cares)?;
+    validate_input(&variables, &minterms, &maxterms)?;
+
+    let dont_cares = get_dont_cares(variable_count, &minterms, &maxterms);
+    let terms = if form == SOP { minterms } else { maxterms };
 
-    let internal_solutions = minimize_internal_with_timeout(
+    let prime_implicants = find_prime_implicants(
         variable_count,
-        maxterms,
-        dont_cares,
-        POS,
-        find_all_solutions,
-        timeout,
+        &terms,
+        &dont_cares,
+        form,
+        &TimeoutSignalNoOp,
+        &ProgressNoOp,
     )?;
+    let prime_implicant_chart = PrimeImplicantChart::new(prime_implicants, &dont_cares);
 
-    Ok(internal_solutions
-        .iter()
-        .map(|solution| Solution::new(solution, &variables, POS))
-        .collect())
+    Ok(ChartTable::new(&prime_implicant_chart, variable_count))
 }
 
 /// The form of a boolean expression.
@@ -320,9 +447,10 @@ pub static DEFAULT_VARIABLES: [&str; 26] = [
     "T", "U", "V", "W", "X", "Y", "Z",
 ];
 
-/// Error types for bad input and timeout.
+/// Error types for bad input, timeout and cancellation.
 #[derive(Debug, thiserror::Error, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub enum Error {
     /// The number of variables was less than 1 or greater than `DEFAULT_VARIABLES.len()`.
     #[error("Invalid variable count: {0} (expected 1 <= variables.len() <= {max_len})", max_len = DEFAULT_VARIABLES.len())]
@@ -333,18 +461,51 @@ pub enum Error {
     /// There were duplicate variables.
     #[error("Duplicate variables are not allowed: {0:?}")]
     DuplicateVariables(HashSet<String>),
-    /// There were terms out of bounds for the given number of variables.
-    #[error("Terms out of bounds: {:?} (expected < {} for {} variables)", offending_terms, 1 << variable_count, variable_count)]
+    /// There were terms out of bounds for the given number of variables,
+    /// or that don't belong to a combination of values of multi-valued variables.
+    #[error(
+        "Terms out of bounds: {:?} (expected < {} for {} variables)",
+        offending_terms,
+        term_count,
+        variable_count
+    )]
     TermOutOfBounds {
         offending_terms: HashSet<u32>,
         variable_count: usize,
+        /// The number of terms, `2^variable_count` or the number of combinations of values of multi-valued variables.
+        term_count: u64,
     },
     /// There were conflicting terms between the given term sets.
     #[error("Conflicting terms between term sets: {0:?}")]
     TermConflict(HashSet<u32>),
+    /// A transition for hazard-free minimization wasn't between two minterms (maxterms in POS form)
+    /// or passed through a term of the other kind.
+    #[error("Invalid transition: {0} -> {1} (expected both to be minterms in SOP form or maxterms in POS form, without a term of the other kind between them)")]
+    InvalidTransition(u32, u32),
+    /// A multi-valued variable had less than 2 or more than [`MAX_VALUE_COUNT`] values, duplicate values,
+    /// or more combinations of values together with the preceding variables than `u32` terms can number.
+    #[error("Invalid values of variable {0} (expected 2 to {max} distinct values and at most 2^32 combinations of values)", max = MAX_VALUE_COUNT)]
+    InvalidValues(String),
+    /// A solution contained a variable that is not among the given inputs.
+    #[error("Unknown variable: {0}")]
+    UnknownVariable(String),
     /// Could not find the solution in time.
     #[error("Could not find the solution in time.")]
     Timeout,
+    /// The [`CancellationToken`] was cancelled before the solution was found.
+    #[error("The minimization was cancelled.")]
+    Cancelled,
+}
+
+fn solve_with_timeout(
+    mut minimizer: Minimizer,
+    timeout: Option<Duration>,
+) -> Result<Vec<Solution>, Error> {
+    if let Some(timeout) = timeout {
+        minimizer = minimizer.timeout(timeout);
+    }
+
+    minimizer.solve()
 }
 
 fn minimize_internal_with_timeout(
@@ -353,57 +514,29 @@ fn minimize_internal_with_timeout(
     dont_cares: HashSet<u32>,
     form: Form,
     find_all_solutions: bool,
+    required_cubes: Vec<Implicant>,
     timeout: Option<Duration>,
 ) -> Result<Vec<Vec<Implicant>>, Error> {
-    let timeout = match timeout {
-        Some(timeout) => timeout,
-        None => {
-            return minimize_internal(
-                variable_count,
-                &terms,
-                &dont_cares,
-                form,
-                find_all_solutions,
-                &TimeoutSignalNoOp,
-            )
-        }
-    };
-
-    let (sender, receiver) = mpsc::channel();
-
-    let outer_timeout_signal = Arc::new(TimeoutSignalAtomicBool::default());
-    let timeout_signal = outer_timeout_signal.clone();
-
-    let mut _worker_thread_builder = thread::Builder::new();
-    #[cfg(debug_assertions)]
-    {
-        _worker_thread_builder =
-            _worker_thread_builder.name("quine-mccluskey worker thread".into());
+    match timeout.and_then(|timeout| Instant::now().checked_add(timeout)) {
+        None => minimize_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            find_all_solutions,
+            &required_cubes,
+            &TimeoutSignalNoOp,
+        ),
+        Some(deadline) => minimize_internal(
+            variable_count,
+            &terms,
+            &dont_cares,
+            form,
+            find_all_solutions,
+            &required_cubes,
+            &TimeoutSignalDeadline::new(deadline),
+        ),
     }
-
-    let worker_thread = _worker_thread_builder
-        .spawn(move || {
-            sender
-                .send(minimize_internal(
-                    variable_count,
-                    &terms,
-                    &dont_cares,
-                    form,
-                    find_all_solutions,
-                    timeout_signal.as_ref(),
-                ))
-                .unwrap();
-        })
-        .expect("failed to spawn quine-mccluskey worker thread");
-
-    let result = receiver.recv_timeout(timeout);
-
-    outer_timeout_signal.signal();
-    worker_thread
-        .join()
-        .expect("failed to join quine-mccluskey worker thread");
-
-    result.unwrap()
 }
 
 fn minimize_internal(
@@ -412,14 +545,46 @@ fn minimize_internal(
     dont_cares: &HashSet<u32>,
     form: Form,
     find_all_solutions: bool,
+    required_cubes: &[Implicant],
     timeout_signal: &impl TTimeoutSignal,
 ) -> Result<Vec<Vec<Implicant>>, Error> {
-    let prime_implicants =
-        find_prime_implicants(variable_count, terms, dont_cares, form, timeout_signal)?;
-    let mut prime_implicant_chart = PrimeImplicantChart::new(prime_implicants, dont_cares);
+    let prime_implicants = find_prime_implicants(
+        variable_count,
+        terms,
+        dont_cares,
+        form,
+        timeout_signal,
+        &ProgressNoOp,
+    )?;
+    let prime_implicant_chart =
+        PrimeImplicantChart::with_required_cubes(prime_implicants, dont_cares, required_cubes);
+
+    solve_chart(
+        prime_implicant_chart,
+        terms,
+        dont_cares,
+        form,
+        find_all_solutions,
+        required_cubes,
+        timeout_signal,
+        &ProgressNoOp,
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
+fn solve_chart(
+    mut prime_implicant_chart: PrimeImplicantChart,
+    terms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    form: Form,
+    find_all_solutions: bool,
+    required_cubes: &[Implicant],
+    timeout_signal: &impl TTimeoutSignal,
+    progress: &impl TProgress,
+) -> Result<Vec<Vec<Implicant>>, Error> {
     let essential_prime_implicants =
-        prime_implicant_chart.simplify(find_all_solutions, timeout_signal)?;
-    let petrick_solutions = Petrick::solve(&prime_implicant_chart, timeout_signal)?;
+        prime_implicant_chart.simplify(find_all_solutions, timeout_signal, progress)?;
+    let petrick_solutions = Petrick::solve(&prime_implicant_chart, timeout_signal, progress)?;
 
     let mut solutions = petrick_solutions
         .iter()
@@ -433,6 +598,9 @@ fn minimize_internal(
 
         solution.variable_sort(form);
         assert!(check_solution(terms, dont_cares, solution));
+        assert!(required_cubes
+            .iter()
+            .all(|&cube| solution.iter().any(|implicant| implicant.covers(cube))));
     }
 
     Ok(solutions)
@@ -444,12 +612,24 @@ fn find_prime_implicants(
     dont_cares: &HashSet<u32>,
     form: Form,
     timeout_signal: &impl TTimeoutSignal,
+    progress: &impl TProgress,
 ) -> Result<Vec<Implicant>, Error> {
-    let terms = terms.union(dont_cares).copied().collect();
+    let terms: HashSet<u32> = terms.union(dont_cares).copied().collect();
+
+    progress.report(Progress::Grouping { terms: terms.len() });
+
     let mut groups = Group::group_terms(variable_count, &terms, form);
     let mut prime_implicants = vec![];
+    let mut round = 0;
 
     while timeout_signal.is_not_signaled() {
+        round += 1;
+        progress.report(Progress::Combining {
+            round,
+            implicants: groups.iter().map(Group::implicant_count).sum(),
+            prime_implicants: prime_implicants.len(),
+        });
+
         let next_groups = (0..groups.len() - 1)
             .map(|i| groups[i].combine(&groups[i + 1]))
             .collect();
@@ -459,7 +639,7 @@ fn find_prime_implicants(
         let next_prime_implicants = groups
             .iter()
             .map_while(|group| {
-                abort = timeout_signal.is_signaled();
+                abort = timeout_signal.is_signaled_now();
                 abort.not().then(|| group.get_prime_implicants(dont_cares))
             })
             .flatten();
@@ -514,6 +694,26 @@ fn validate_input(
     terms1: &HashSet<u32>,
     terms2: &HashSet<u32>,
 ) -> Result<(), Error> {
+    validate_variables(variables)?;
+
+    let all_terms: HashSet<u32> = terms1.union(terms2).copied().collect();
+    let terms_out_of_bounds: HashSet<u32> = all_terms
+        .into_iter()
+        .filter(|&term| term >= 1 << variables.len())
+        .collect();
+
+    if !terms_out_of_bounds.is_empty() {
+        return Err(Error::TermOutOfBounds {
+            offending_terms: terms_out_of_bounds,
+            variable_count: variables.len(),
+            term_count: 1 << variables.len(),
+        });
+    }
+
+    validate_conflicts(terms1, terms2)
+}
+
+fn validate_variables(variables: &[String]) -> Result<(), Error> {
     if variables.is_empty() || variables.len() > DEFAULT_VARIABLES.len() {
         return Err(Error::InvalidVariableCount(variables.len()));
     }
@@ -539,19 +739,10 @@ fn validate_input(
         return Err(Error::DuplicateVariables(duplicates));
     }
 
-    let all_terms: HashSet<u32> = terms1.union(terms2).copied().collect();
-    let terms_out_of_bounds: HashSet<u32> = all_terms
-        .into_iter()
-        .filter(|&term| term >= 1 << variables.len())
-        .collect();
-
-    if !terms_out_of_bounds.is_empty() {
-        return Err(Error::TermOutOfBounds {
-            offending_terms: terms_out_of_bounds,
-            variable_count: variables.len(),
-        });
-    }
+    Ok(())
+}
 
+fn validate_conflicts(terms1: &HashSet<u32>, terms2: &HashSet<u32>) -> Result<(), Error> {
     let conflicts: HashSet<u32> = terms1.intersection(terms2).copied().collect();
 
     if !conflicts.is_empty() {
@@ -648,6 +839,7 @@ mod tests {
                 &dont_cares,
                 form,
                 &TimeoutSignalNoOp,
+                &ProgressNoOp,
             )
             .unwrap();
 
@@ -0,0 +1,789 @@
+use std::collections::HashSet;
+use std::mem;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::anytime::{self, AnytimeSolutions};
+use crate::implicant::{Implicant, VariableSort};
+use crate::prime_implicant_chart::PrimeImplicantChart;
+use crate::progress::{ProgressNoOp, TProgress};
+use crate::timeout_signal::{
+    CancellationToken, TTimeoutSignal, TimeoutSignalDeadline, TimeoutSignalEither,
+    TimeoutSignalNoOp, TimeoutSignalTimer,
+};
+use crate::{
+    check_solution, find_prime_implicants, get_dont_cares, own_variables, solve_chart,
+    validate_conflicts, validate_input, validate_variables, Error, Form, Progress, Solution,
+};
+
+/// Minimizes a boolean function configured step by step, optionally editing and solving it again.
+///
+/// The builder methods set the terms and options [`minimize`](crate::minimize) takes as arguments,
+/// with the same defaults as passing `SOP`, `false` and `None` to it. Terms listed in none of
+/// [`minterms`](Minimizer::minterms), [`maxterms`](Minimizer::maxterms) and [`dont_cares`](Minimizer::dont_cares)
+/// are don't cares, unless the don't cares and only one of the other two are listed, which then gets the rest.
+/// Listing terms again after solving starts over with only the lists given since.
+///
+/// The `add_*` methods instead move a single term into the minterms, maxterms or don't cares.
+/// The first call to [`solve`](Minimizer::solve) finds the prime implicants, after which each edit only updates
+/// the prime implicants containing the edited term and the affected rows and column of the prime implicant chart,
+/// so solving again only has to simplify the chart.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let mut minimizer = qmc::Minimizer::new(&["A", "B", "C"])
+///     .minterms(&[0, 5])
+///     .maxterms(&[1, 3, 4, 6])
+///     .form(qmc::SOP);
+///
+/// let solutions = minimizer.solve().unwrap();
+/// assert_eq!(solutions[0].to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+///
+/// minimizer.add_maxterm(0).unwrap();
+///
+/// let solutions = minimizer.solve().unwrap();
+/// assert_eq!(solutions[0].to_string(), "A ∧ C");
+/// ```
+pub struct Minimizer {
+    variables: Vec<String>,
+    form: Form,
+    find_all_solutions: bool,
+    timeout: Option<Duration>,
+    use_timeout_thread: bool,
+    cancellation_token: Option<CancellationToken>,
+    progress: Option<ProgressCallback>,
+    /// The term lists given since the last time the state was built from them.
+    minterms: Option<Vec<u32>>,
+    maxterms: Option<Vec<u32>>,
+    dont_cares: Option<Vec<u32>>,
+    state: State,
+}
+
+type ProgressCallback = Arc<dyn Fn(&Progress) + Send + Sync>;
+
+#[derive(Default)]
+struct State {
+    /// The minterms in SOP form and the maxterms in POS form.
+    terms: HashSet<u32>,
+    /// The maxterms in SOP form and the minterms in POS form.
+    other_terms: HashSet<u32>,
+    cache: Option<Cache>,
+}
+
+struct Cache {
+    dont_cares: HashSet<u32>,
+    /// All prime implicants of the terms and don't cares, including the ones covering only don't cares.
+    prime_implicants: HashSet<Implicant>,
+    /// The unsimplified chart of the prime implicants covering at least one term.
+    chart: PrimeImplicantChart,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Term,
+    OtherTerm,
+    DontCare,
+}
+
+impl Minimizer {
+    /// Creates a minimizer for a function of the given `variables` with every term as a don't care.
+    ///
+    /// The variables are checked when solving or editing, for the same reasons as by [`minimize`](crate::minimize).
+    pub fn new<T: AsRef<str>>(variables: &[T]) -> Self {
+        Minimizer {
+            variables: own_variables(variables),
+            form: Form::SOP,
+            find_all_solutions: false,
+            timeout: None,
+            use_timeout_thread: false,
+            cancellation_token: None,
+            progress: None,
+            minterms: None,
+            maxterms: None,
+            dont_cares: None,
+            state: State::default(),
+        }
+    }
+
+    /// Lists the minterms, replacing any listed before.
+    pub fn minterms(mut self, minterms: &[u32]) -> Self {
+        self.minterms = Some(minterms.to_vec());
+        self
+    }
+
+    /// Lists the maxterms, replacing any listed before.
+    pub fn maxterms(mut self, maxterms: &[u32]) -> Self {
+        self.maxterms = Some(maxterms.to_vec());
+        self
+    }
+
+    /// Lists the don't cares, replacing any listed before.
+    pub fn dont_cares(mut self, dont_cares: &[u32]) -> Self {
+        self.dont_cares = Some(dont_cares.to_vec());
+        self
+    }
+
+    /// Sets the form of the solutions, [`SOP`](Form::SOP) by default.
+    pub fn form(mut self, form: Form) -> Self {
+        if form != self.form {
+            let state = &mut self.state;
+
+            mem::swap(&mut state.terms, &mut state.other_terms);
+            state.cache = None;
+            self.form = form;
+        }
+
+        self
+    }
+
+    /// Sets whether to find all minimal solutions instead of one.
+    pub fn all_solutions(mut self, find_all_solutions: bool) -> Self {
+        self.find_all_solutions = find_all_solutions;
+        self
+    }
+
+    /// Sets the time after which solving fails with [`Error::Timeout`].
+    ///
+    /// The deadline is checked on the calling thread as solving goes on, so no thread is spawned for it.
+    /// Between the steps that don't read the clock, solving may go on for a little while after the deadline.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets whether to spawn a thread for each [`solve`](Minimizer::solve) that signals the [`timeout`](Minimizer::timeout)
+    /// as soon as it hits, `false` by default.
+    ///
+    /// Solving still runs on the calling thread, which then notices the timeout at its very next check
+    /// instead of the next one reading the clock, at the cost of spawning and joining the thread.
+    pub fn timeout_thread(mut self, use_timeout_thread: bool) -> Self {
+        self.use_timeout_thread = use_timeout_thread;
+        self
+    }
+
+    /// Sets a token for cancelling the minimization, after which solving fails with [`Error::Cancelled`].
+    ///
+    /// It can be combined with a [`timeout`](Minimizer::timeout), whichever comes first stopping the minimization.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let token = qmc::CancellationToken::new();
+    /// let mut minimizer = qmc::Minimizer::new(&["A", "B"])
+    ///     .minterms(&[1, 3])
+    ///     .cancellation_token(&token);
+    ///
+    /// // Usually called from another thread, e.g. when the user closes a dialog.
+    /// token.cancel();
+    ///
+    /// assert!(matches!(minimizer.solve(), Err(qmc::Error::Cancelled)));
+    /// ```
+    pub fn cancellation_token(mut self, token: &CancellationToken) -> Self {
+        self.cancellation_token = Some(token.clone());
+        self
+    }
+
+    /// Sets a callback receiving the [`Progress`] of solving.
+    ///
+    /// After an edit, solving again skips finding the prime implicants, reporting only the chart and Petrick's method.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let steps = Arc::new(Mutex::new(vec![]));
+    /// let steps_clone = steps.clone();
+    ///
+    /// qmc::Minimizer::new(&["A", "B"])
+    ///     .minterms(&[1, 3])
+    ///     .progress(move |progress| steps_clone.lock().unwrap().push(progress.clone()))
+    ///     .solve()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(steps.lock().unwrap()[0], qmc::Progress::Grouping { terms: 4 });
+    /// ```
+    pub fn progress(mut self, callback: impl Fn(&Progress) + Send + Sync + 'static) -> Self {
+        self.progress = Some(Arc::new(callback));
+        self
+    }
+
+    /// Makes the term a minterm.
+    ///
+    /// Returns an [`Error`] if the term doesn't fit into the variables
+    /// or for the same reasons as [`solve`](Minimizer::solve) if terms were listed since solving.
+    pub fn add_minterm(&mut self, term: u32) -> Result<(), Error> {
+        let kind = if self.form == Form::SOP {
+            Kind::Term
+        } else {
+            Kind::OtherTerm
+        };
+
+        self.set(term, kind)
+    }
+
+    /// Makes the term a maxterm.
+    ///
+    /// Returns an [`Error`] if the term doesn't fit into the variables
+    /// or for the same reasons as [`solve`](Minimizer::solve) if terms were listed since solving.
+    pub fn add_maxterm(&mut self, term: u32) -> Result<(), Error> {
+        let kind = if self.form == Form::POS {
+            Kind::Term
+        } else {
+            Kind::OtherTerm
+        };
+
+        self.set(term, kind)
+    }
+
+    /// Makes the term a don't care, removing it from the minterms or maxterms.
+    ///
+    /// Returns an [`Error`] if the term doesn't fit into the variables
+    /// or for the same reasons as [`solve`](Minimizer::solve) if terms were listed since solving.
+    pub fn add_dont_care(&mut self, term: u32) -> Result<(), Error> {
+        self.set(term, Kind::DontCare)
+    }
+
+    /// Minimizes the function as it is now.
+    ///
+    /// Returns an [`Error`] for the same reasons as [`minimize`](crate::minimize),
+    /// or [`Error::TermConflict`] if a term is listed both as a minterm or maxterm and as a don't care.
+    /// The prime implicants and the chart are kept even if solving times out or is cancelled.
+    pub fn solve(&mut self) -> Result<Vec<Solution>, Error> {
+        self.build_state()?;
+
+        let variable_count = self.variables.len() as u32;
+        let (form, find_all_solutions) = (self.form, self.find_all_solutions);
+        let use_timeout_thread = self.use_timeout_thread;
+
+        let (state, token, progress) = (&mut self.state, &self.cancellation_token, &self.progress);
+
+        if token
+            .as_ref()
+            .map_or(false, CancellationToken::is_cancelled)
+        {
+            return Err(Error::Cancelled);
+        }
+
+        let deadline = self
+            .timeout
+            .and_then(|timeout| Instant::now().checked_add(timeout));
+
+        let internal_solutions = match (deadline, token) {
+            (None, None) => state.solve(
+                variable_count,
+                form,
+                find_all_solutions,
+                &TimeoutSignalNoOp,
+                progress,
+            ),
+            (None, Some(token)) => {
+                state.solve(variable_count, form, find_all_solutions, token, progress)
+            }
+            (Some(deadline), None) if use_timeout_thread => state.solve(
+                variable_count,
+                form,
+                find_all_solutions,
+                &TimeoutSignalTimer::spawn(deadline),
+                progress,
+            ),
+            (Some(deadline), None) => state.solve(
+                variable_count,
+                form,
+                find_all_solutions,
+                &TimeoutSignalDeadline::new(deadline),
+                progress,
+            ),
+            (Some(deadline), Some(token)) if use_timeout_thread => state.solve(
+                variable_count,
+                form,
+                find_all_solutions,
+                &TimeoutSignalEither::new(&TimeoutSignalTimer::spawn(deadline), token),
+                progress,
+            ),
+            (Some(deadline), Some(token)) => state.solve(
+                variable_count,
+                form,
+                find_all_solutions,
+                &TimeoutSignalEither::new(&TimeoutSignalDeadline::new(deadline), token),
+                progress,
+            ),
+        }
+        .map_err(|error| match (error, token) {
+            (Error::Timeout, Some(token)) if token.is_cancelled() => Error::Cancelled,
+            (error, _) => error,
+        })?;
+
+        Ok(internal_solutions
+            .iter()
+            .map(|solution| Solution::new(solution, &self.variables, form))
+            .collect())
+    }
+
+    /// Minimizes the function like [`solve`](Minimizer::solve), but returns a valid solution
+    /// flagged as not optimal instead of [`Error::Timeout`] when the [`timeout`](Minimizer::timeout) hits.
+    ///
+    /// If the prime implicants were found in time, the solution covers the prime implicant chart greedily,
+    /// and the optimality gap compares its number of terms to a lower bound for a minimal solution.
+    /// Otherwise each term is grown into a cube on its own and the gap is unknown.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    ///
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let variables = ["A", "B", "C"];
+    /// let (minterms, maxterms) = ([0, 1, 2, 5, 6, 7], [3, 4]);
+    ///
+    /// let anytime = qmc::Minimizer::new(&variables)
+    ///     .minterms(&minterms)
+    ///     .maxterms(&maxterms)
+    ///     .timeout(Duration::ZERO)
+    ///     .solve_anytime()
+    ///     .unwrap();
+    ///
+    /// assert!(!anytime.is_optimal);
+    /// assert!(anytime.solutions[0]
+    ///     .verify_against(&variables, &minterms, &maxterms)
+    ///     .unwrap()
+    ///     .is_equivalent());
+    /// ```
+    pub fn solve_anytime(&mut self) -> Result<AnytimeSolutions, Error> {
+        match self.solve() {
+            Ok(solutions) => Ok(AnytimeSolutions {
+                solutions,
+                is_optimal: true,
+                optimality_gap: Some(0),
+            }),
+            Err(Error::Timeout) => {
+                let (solution, optimality_gap) = self
+                    .state
+                    .approximate(self.variables.len() as u32, self.form);
+
+                Ok(AnytimeSolutions {
+                    solutions: vec![Solution::new(&solution, &self.variables, self.form)],
+                    is_optimal: false,
+                    optimality_gap,
+                })
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    fn set(&mut self, term: u32, kind: Kind) -> Result<(), Error> {
+        self.build_state()?;
+
+        let variable_count = self.variables.len();
+
+        if term >= 1 << variable_count {
+            return Err(Error::TermOutOfBounds {
+                offending_terms: HashSet::from([term]),
+                variable_count,
+                term_count: 1 << variable_count,
+            });
+        }
+
+        self.state.set(variable_count as u32, term, kind);
+
+        Ok(())
+    }
+
+    /// Checks the variables and replaces the state with the listed terms if there are any.
+    fn build_state(&mut self) -> Result<(), Error> {
+        validate_variables(&self.variables)?;
+
+        if self.minterms.is_none() && self.maxterms.is_none() && self.dont_cares.is_none() {
+            return Ok(());
+        }
+
+        let to_set =
+            |terms: &Option<Vec<u32>>| terms.iter().flatten().copied().collect::<HashSet<_>>();
+        let (mut minterms, mut maxterms, dont_cares) = (
+            to_set(&self.minterms),
+            to_set(&self.maxterms),
+            to_set(&self.dont_cares),
+        );
+
+        validate_input(
+            &self.variables,
+            &minterms,
+            &maxterms.union(&dont_cares).copied().collect(),
+        )?;
+        validate_conflicts(&maxterms, &dont_cares)?;
+
+        let variable_count = self.variables.len() as u32;
+
+        if self.dont_cares.is_some() && self.maxterms.is_none() && self.minterms.is_some() {
+            maxterms = get_dont_cares(variable_count, &minterms, &dont_cares);
+        } else if self.dont_cares.is_some() && self.minterms.is_none() && self.maxterms.is_some() {
+            minterms = get_dont_cares(variable_count, &maxterms, &dont_cares);
+        }
+
+        let (terms, other_terms) = if self.form == Form::SOP {
+            (minterms, maxterms)
+        } else {
+            (maxterms, minterms)
+        };
+
+        self.state = State {
+            terms,
+            other_terms,
+            cache: None,
+        };
+        self.minterms = None;
+        self.maxterms = None;
+        self.dont_cares = None;
+
+        Ok(())
+    }
+}
+
+impl State {
+    fn kind(&self, term: u32) -> Kind {
+        if self.terms.contains(&term) {
+            Kind::Term
+        } else if self.other_terms.contains(&term) {
+            Kind::OtherTerm
+        } else {
+            Kind::DontCare
+        }
+    }
+
+    fn set(&mut self, variable_count: u32, term: u32, kind: Kind) {
+        let old_kind = self.kind(term);
+
+        if kind == old_kind {
+            return;
+        }
+
+        self.terms.remove(&term);
+        self.other_terms.remove(&term);
+
+        match kind {
+            Kind::Term => self.terms.insert(term),
+            Kind::OtherTerm => self.other_terms.insert(term),
+            Kind::DontCare => false,
+        };
+
+        if let Some(cache) = &mut self.cache {
+            cache.update(
+                variable_count,
+                term,
+                old_kind,
+                kind,
+                &self.terms,
+                &self.other_terms,
+            );
+        }
+    }
+
+    fn solve(
+        &mut self,
+        variable_count: u32,
+        form: Form,
+        find_all_solutions: bool,
+        timeout_signal: &impl TTimeoutSignal,
+        progress: &Option<ProgressCallback>,
+    ) -> Result<Vec<Vec<Implicant>>, Error> {
+        match progress {
+            Some(callback) => self.solve_with_progress(
+                variable_count,
+                form,
+                find_all_solutions,
+                timeout_signal,
+                &callback.as_ref(),
+            ),
+            None => self.solve_with_progress(
+                variable_count,
+                form,
+                find_all_solutions,
+                timeout_signal,
+                &ProgressNoOp,
+            ),
+        }
+    }
+
+    fn solve_with_progress(
+        &mut self,
+        variable_count: u32,
+        form: Form,
+        find_all_solutions: bool,
+        timeout_signal: &impl TTimeoutSignal,
+        progress: &impl TProgress,
+    ) -> Result<Vec<Vec<Implicant>>, Error> {
+        if self.cache.is_none() {
+            self.cache = Some(Cache::new(
+                variable_count,
+                &self.terms,
+                &self.other_terms,
+                form,
+                timeout_signal,
+                progress,
+            )?);
+        }
+
+        let cache = self.cache.as_ref().unwrap();
+
+        solve_chart(
+            cache.chart.clone(),
+            &self.terms,
+            &cache.dont_cares,
+            form,
+            find_all_solutions,
+            &[],
+            timeout_signal,
+            progress,
+        )
+    }
+
+    /// Returns a valid solution without solving the chart, along with the optimality gap if it is known.
+    fn approximate(&self, variable_count: u32, form: Form) -> (Vec<Implicant>, Option<usize>) {
+        match &self.cache {
+            Some(cache) => {
+                let (mut solution, lower_bound) = anytime::greedy_cover(&cache.chart);
+
+                solution.variable_sort(form);
+                assert!(check_solution(&self.terms, &cache.dont_cares, &solution));
+
+                let optimality_gap = solution.len() - lower_bound;
+
+                (solution, Some(optimality_gap))
+            }
+            None => {
+                let mut solution =
+                    anytime::expansion_cover(variable_count, &self.terms, &self.other_terms);
+                let dont_cares = get_dont_cares(variable_count, &self.terms, &self.other_terms);
+
+                solution.variable_sort(form);
+                assert!(check_solution(&self.terms, &dont_cares, &solution));
+
+                (solution, None)
+            }
+        }
+    }
+}
+
+impl Cache {
+    fn new(
+        variable_count: u32,
+        terms: &HashSet<u32>,
+        other_terms: &HashSet<u32>,
+        form: Form,
+        timeout_signal: &impl TTimeoutSignal,
+        progress: &impl TProgress,
+    ) -> Result<Self, Error> {
+        let dont_cares = get_dont_cares(variable_count, terms, other_terms);
+        let covered_terms = terms.union(&dont_cares).copied().collect();
+
+        // Without don't cares, the prime implicants covering only don't cares are kept as well.
+        let prime_implicants = find_prime_implicants(
+            variable_count,
+            &covered_terms,
+            &HashSet::new(),
+            form,
+            timeout_signal,
+            progress,
+        )?;
+        let chart = PrimeImplicantChart::new(
+            prime_implicants
+                .iter()
+                .copied()
+                .filter(|&implicant| covers_any(implicant, terms))
+                .collect(),
+            &dont_cares,
+        );
+
+        Ok(Cache {
+            dont_cares,
+            prime_implicants: prime_implicants.into_iter().collect(),
+            chart,
+        })
+    }
+
+    fn update(
+        &mut self,
+        variable_count: u32,
+        term: u32,
+        old_kind: Kind,
+        kind: Kind,
+        terms: &HashSet<u32>,
+        other_terms: &HashSet<u32>,
+    ) {
+        if old_kind == Kind::DontCare {
+            self.dont_cares.remove(&term);
+        } else if kind == Kind::DontCare {
+            self.dont_cares.insert(term);
+        }
+
+        let (removed, added) = if kind == Kind::OtherTerm {
+            self.shrink(term)
+        } else if old_kind == Kind::OtherTerm {
+            self.grow(variable_count, term, other_terms)
+        } else {
+            (vec![], vec![])
+        };
+
+        if old_kind == Kind::Term {
+            self.chart.remove_term(term);
+        }
+
+        for &implicant in &removed {
+            self.chart.remove_implicant(implicant);
+        }
+
+        if kind == Kind::Term {
+            self.chart.insert_term(term);
+        }
+
+        // The rows containing the term may have gained or lost their only term.
+        let changed_rows = self
+            .prime_implicants
+            .iter()
+            .copied()
+            .filter(|implicant| implicant.contains(term))
+            .chain(added)
+            .collect::<HashSet<_>>();
+
+        for implicant in changed_rows {
+            self.chart.remove_implicant(implicant);
+
+            if covers_any(implicant, terms) {
+                self.chart.insert_implicant(implicant);
+            }
+        }
+    }
+
+    /// Updates the prime implicants after the term became a term of the other kind,
+    /// returning the removed and the added ones.
+    ///
+    /// The prime implicants without the term stay prime. The new ones are the largest
+    /// halves of the removed ones that avoid the term and aren't covered by another prime implicant.
+    fn shrink(&mut self, term: u32) -> (Vec<Implicant>, Vec<Implicant>) {
+        let removed = self
+            .prime_implicants
+            .iter()
+            .copied()
+            .filter(|implicant| implicant.contains(term))
+            .collect::<Vec<_>>();
+
+        for implicant in &removed {
+            self.prime_implicants.remove(implicant);
+        }
+
+        let mut halves = HashSet::new();
+
+        for implicant in &removed {
+            let mut mask = implicant.mask();
+
+            while mask != 0 {
+                let bit = mask & mask.wrapping_neg();
+                mask &= mask - 1;
+
+                halves.insert(Implicant::from_parts(
+                    implicant.value() | !term & bit,
+                    implicant.mask() & !bit,
+                ));
+            }
+        }
+
+        let added = halves
+            .iter()
+            .copied()
+            .filter(|&half| {
+                !self
+                    .prime_implicants
+                    .iter()
+                    .chain(&halves)
+                    .any(|&other| other != half && other.covers(half))
+            })
+            .collect::<Vec<_>>();
+
+        self.prime_implicants.extend(&added);
+
+        (removed, added)
+    }
+
+    /// Updates the prime implicants after the term stopped being a term of the other kind,
+    /// returning the removed and the added ones.
+    ///
+    /// The new prime implicants are the largest cubes containing the term, found by growing it
+    /// one variable at a time. The old ones stay prime unless a new one covers them.
+    fn grow(
+        &mut self,
+        variable_count: u32,
+        term: u32,
+        other_terms: &HashSet<u32>,
+    ) -> (Vec<Implicant>, Vec<Implicant>) {
+        let all_variables = (1u32 << variable_count) - 1;
+        let mut masks = vec![0];
+        let mut added = vec![];
+
+        while !masks.is_empty() {
+            let mut next_masks = HashSet::new();
+
+            for &mask in &masks {
+                let mut is_largest = true;
+                let mut free_variables = all_variables & !mask;
+
+                while free_variables != 0 {
+                    let bit = free_variables & free_variables.wrapping_neg();
+                    free_variables &= free_variables - 1;
+
+                    // The cube doubles into its mirror image along the variable.
+                    let mirror = Implicant::from_parts(term ^ bit, mask);
+
+                    if next_masks.contains(&(mask | bit))
+                        || mirror
+                            .get_terms()
+                            .iter()
+                            .all(|term| !other_terms.contains(term))
+                    {
+                        next_masks.insert(mask | bit);
+                        is_largest = false;
+                    }
+                }
+
+                if is_largest {
+                    added.push(Implicant::from_parts(term, mask));
+                }
+            }
+
+            masks = next_masks.into_iter().collect();
+        }
+
+        let removed = self
+            .prime_implicants
+            .iter()
+            .copied()
+            .filter(|&implicant| added.iter().any(|other| other.covers(implicant)))
+            .collect::<Vec<_>>();
+
+        for implicant in &removed {
+            self.prime_implicants.remove(implicant);
+        }
+
+        self.prime_implicants.extend(&added);
+
+        (removed, added)
+    }
+}
+
+fn covers_any(implicant: Implicant, terms: &HashSet<u32>) -> bool {
+    if 1 << implicant.wildcard_count() < terms.len() {
+        implicant
+            .get_terms()
+            .iter()
+            .any(|term| terms.contains(term))
+    } else {
+        terms.iter().any(|&term| implicant.contains(term))
+    }
+}
@@ -0,0 +1,479 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+use std::time::{Duration, Instant};
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::timeout_signal::{TTimeoutSignal, TimeoutSignalDeadline, TimeoutSignalNoOp};
+use crate::{validate_conflicts, validate_variables, Error};
+
+/// The maximum number of values of a [`MultiValuedVariable`].
+pub const MAX_VALUE_COUNT: usize = 32;
+
+/// A variable taking one of two or more named values, e.g. the state of a state machine.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MultiValuedVariable {
+    pub name: String,
+    pub values: Vec<String>,
+}
+
+/// A literal restricting a [`MultiValuedVariable`] to a set of its values.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SetLiteral {
+    pub name: String,
+    /// The values in the order of [`MultiValuedVariable::values`].
+    pub values: Vec<String>,
+}
+
+/// A minimized sum of products of [`SetLiteral`]s, see [`minimize_multi_valued`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MultiValuedSolution {
+    One,
+    Zero,
+    SOP(Vec<Vec<SetLiteral>>),
+}
+
+/// A product term with the set of allowed values of each variable as a bit mask, the first value being the lowest bit.
+type Cube = Vec<u32>;
+
+impl MultiValuedVariable {
+    pub fn new<T: AsRef<str>>(name: &str, values: &[T]) -> Self {
+        MultiValuedVariable {
+            name: name.to_owned(),
+            values: values
+                .iter()
+                .map(|value| value.as_ref().to_owned())
+                .collect(),
+        }
+    }
+
+    /// Creates a two-valued variable with the values `0` and `1`.
+    pub fn binary(name: &str) -> Self {
+        MultiValuedVariable::new(name, &["0", "1"])
+    }
+
+    /// Returns the term of the given values of the `variables`, or `None` if a value is unknown
+    /// or the number of values doesn't match.
+    ///
+    /// Terms number the combinations of values with the first variable being the most significant digit,
+    /// e.g. the term of `["Run", "1"]` is `1 * 2 + 1 = 3` for the variables `Mode ∈ {Idle, Run, Halt}` and `x ∈ {0, 1}`.
+    pub fn term<T: AsRef<str>>(variables: &[MultiValuedVariable], values: &[T]) -> Option<u32> {
+        if variables.len() != values.len() {
+            return None;
+        }
+
+        let mut term = 0u64;
+
+        for (variable, value) in variables.iter().zip(values) {
+            let index = variable
+                .values
+                .iter()
+                .position(|name| name == value.as_ref())?;
+
+            term = term * variable.values.len() as u64 + index as u64;
+        }
+
+        u32::try_from(term).ok()
+    }
+}
+
+impl Display for SetLiteral {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.values.len() == 1 {
+            write!(f, "{} = {}", self.name, self.values[0])
+        } else {
+            write!(f, "{} ∈ {{{}}}", self.name, self.values.join(", "))
+        }
+    }
+}
+
+impl Display for MultiValuedSolution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let products = match self {
+            MultiValuedSolution::One => return write!(f, "1"),
+            MultiValuedSolution::Zero => return write!(f, "0"),
+            MultiValuedSolution::SOP(products) => products,
+        };
+
+        let products = products
+            .iter()
+            .map(|literals| {
+                let product = literals
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(" ∧ ");
+
+                if literals.len() > 1 && products.len() > 1 {
+                    format!("({})", product)
+                } else {
+                    product
+                }
+            })
+            .collect::<Vec<_>>();
+
+        write!(f, "{}", products.join(" ∨ "))
+    }
+}
+
+/// Minimizes the boolean function of multi-valued variables represented by the given `minterms` and `dont_cares`
+/// into a sum of products of [`SetLiteral`]s.
+///
+/// Instead of encoding the values in binary variables, the minimizer merges product terms over sets of values,
+/// e.g. `Mode = Idle ∧ x = 1` and `Mode = Run ∧ x = 1` into `Mode ∈ {Idle, Run} ∧ x = 1`.
+/// Variables that may take any value are left out of a product. The result has the fewest products
+/// and among those the fewest literals. Terms are numbered as described in [`MultiValuedVariable::term`].
+///
+/// Returns [`Error::InvalidValues`] if a variable has less than 2 or more than [`MAX_VALUE_COUNT`] values,
+/// duplicate values or more combinations of values with the preceding variables than `u32` terms can number, and [`Error::TermOutOfBounds`] if a term doesn't belong to a combination of values.
+/// Otherwise returns an [`Error`] for the same bad input as [`minimize_minterms`](crate::minimize_minterms),
+/// and [`Error::Timeout`] if the solution isn't found within the `timeout`.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+/// use qmc::MultiValuedVariable;
+///
+/// let variables = [
+///     MultiValuedVariable::new("Mode", &["Idle", "Run", "Halt"]),
+///     MultiValuedVariable::binary("x"),
+/// ];
+/// let minterms = [["Idle", "1"], ["Run", "1"], ["Halt", "0"]]
+///     .iter()
+///     .map(|values| MultiValuedVariable::term(&variables, values).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let solution = qmc::minimize_multi_valued(&variables, &minterms, &[], None).unwrap();
+///
+/// assert_eq!(solution.to_string(), "(Mode ∈ {Idle, Run} ∧ x = 1) ∨ (Mode = Halt ∧ x = 0)");
+/// ```
+pub fn minimize_multi_valued(
+    variables: &[MultiValuedVariable],
+    minterms: &[u32],
+    dont_cares: &[u32],
+    timeout: Option<Duration>,
+) -> Result<MultiValuedSolution, Error> {
+    let names = variables
+        .iter()
+        .map(|variable| variable.name.clone())
+        .collect::<Vec<_>>();
+
+    validate_variables(&names)?;
+
+    for variable in variables {
+        let values = variable.values.iter().collect::<HashSet<_>>();
+
+        if !(2..=MAX_VALUE_COUNT).contains(&variable.values.len())
+            || values.len() != variable.values.len()
+        {
+            return Err(Error::InvalidValues(variable.name.clone()));
+        }
+    }
+
+    let minterms = minterms.iter().copied().collect::<HashSet<_>>();
+    let dont_cares = dont_cares.iter().copied().collect::<HashSet<_>>();
+    let term_count = variables.iter().try_fold(1u64, |term_count, variable| {
+        term_count
+            .checked_mul(variable.values.len() as u64)
+            .filter(|&term_count| term_count <= 1 << u32::BITS)
+            .ok_or_else(|| Error::InvalidValues(variable.name.clone()))
+    })?;
+    let offending_terms = minterms
+        .union(&dont_cares)
+        .copied()
+        .filter(|&term| term as u64 >= term_count)
+        .collect::<HashSet<_>>();
+
+    if !offending_terms.is_empty() {
+        return Err(Error::TermOutOfBounds {
+            offending_terms,
+            variable_count: variables.len(),
+            term_count,
+        });
+    }
+
+    validate_conflicts(&minterms, &dont_cares)?;
+
+    match timeout.and_then(|timeout| Instant::now().checked_add(timeout)) {
+        None => minimize_internal(variables, &minterms, &dont_cares, &TimeoutSignalNoOp),
+        Some(deadline) => minimize_internal(
+            variables,
+            &minterms,
+            &dont_cares,
+            &TimeoutSignalDeadline::new(deadline),
+        ),
+    }
+}
+
+fn minimize_internal(
+    variables: &[MultiValuedVariable],
+    minterms: &HashSet<u32>,
+    dont_cares: &HashSet<u32>,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<MultiValuedSolution, Error> {
+    let value_counts = variables
+        .iter()
+        .map(|variable| variable.values.len())
+        .collect::<Vec<_>>();
+    let cube_of = |term: u32| {
+        let mut term = term;
+        let mut cube = vec![0; value_counts.len()];
+
+        for (i, &value_count) in value_counts.iter().enumerate().rev() {
+            cube[i] = 1 << (term % value_count as u32);
+            term /= value_count as u32;
+        }
+
+        cube
+    };
+
+    let care_cubes = minterms
+        .iter()
+        .map(|&term| cube_of(term))
+        .collect::<Vec<_>>();
+    let primes = find_primes(
+        minterms.union(dont_cares).map(|&term| cube_of(term)),
+        timeout_signal,
+    )?;
+    let cover = find_cover(&primes, &care_cubes, &value_counts, timeout_signal)?;
+
+    Ok(to_solution(variables, &cover, &value_counts))
+}
+
+fn full_mask(value_count: usize) -> u32 {
+    u32::MAX >> (32 - value_count)
+}
+
+/// Finds the prime implicants by iterated consensus, widening the set literal of one variable
+/// to the union of those of two cubes whose other set literals intersect.
+///
+/// Cubes contained in another one are dropped as soon as they are found, so only maximal cubes are combined
+/// and the subsets of values in between are never generated.
+fn find_primes(
+    terms: impl Iterator<Item = Cube>,
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Cube>, Error> {
+    let mut primes: Vec<Cube> = vec![];
+    let mut queue = terms.collect::<Vec<_>>();
+
+    while let Some(cube) = queue.pop() {
+        if timeout_signal.is_signaled_now() {
+            return Err(Error::Timeout);
+        }
+
+        if primes.iter().any(|prime| contains(prime, &cube)) {
+            continue;
+        }
+
+        primes.retain(|prime| !contains(&cube, prime));
+
+        for prime in &primes {
+            for i in 0..cube.len() {
+                if let Some(consensus) = consensus(&cube, prime, i) {
+                    if !contains(&cube, &consensus) && !contains(prime, &consensus) {
+                        queue.push(consensus);
+                    }
+                }
+            }
+        }
+
+        primes.push(cube);
+    }
+
+    primes.sort_unstable();
+
+    Ok(primes)
+}
+
+/// Returns the cube of terms in `a` or `b` with the union of their values of variable `i`
+/// and the intersection of the others, if none of those is empty.
+fn consensus(a: &[u32], b: &[u32], i: usize) -> Option<Cube> {
+    a.iter()
+        .zip(b)
+        .enumerate()
+        .map(|(j, (&a, &b))| {
+            let mask = if j == i { a | b } else { a & b };
+
+            if mask == 0 {
+                None
+            } else {
+                Some(mask)
+            }
+        })
+        .collect()
+}
+
+fn contains(cube: &[u32], other: &[u32]) -> bool {
+    cube.iter()
+        .zip(other)
+        .all(|(mask, other)| other & !mask == 0)
+}
+
+fn covers(cube: &[u32], term: &[u32]) -> bool {
+    cube.iter().zip(term).all(|(mask, value)| mask & value != 0)
+}
+
+fn literal_count(cube: &[u32], value_counts: &[usize]) -> usize {
+    cube.iter()
+        .zip(value_counts)
+        .filter(|&(&mask, &value_count)| mask != full_mask(value_count))
+        .count()
+}
+
+/// Finds the cover of the terms with the fewest primes and among those the fewest literals by branch and bound.
+fn find_cover(
+    primes: &[Cube],
+    terms: &[Cube],
+    value_counts: &[usize],
+    timeout_signal: &impl TTimeoutSignal,
+) -> Result<Vec<Cube>, Error> {
+    struct Search<'a> {
+        covering: Vec<Vec<usize>>,
+        costs: Vec<usize>,
+        chosen: Vec<usize>,
+        best: Option<(usize, usize, Vec<usize>)>,
+        covered: Vec<usize>,
+        primes: &'a [Cube],
+        terms: &'a [Cube],
+    }
+
+    impl Search<'_> {
+        fn search(
+            &mut self,
+            literal_count: usize,
+            timeout_signal: &impl TTimeoutSignal,
+        ) -> Result<(), Error> {
+            if timeout_signal.is_signaled() {
+                return Err(Error::Timeout);
+            }
+
+            if let Some((count, literals, _)) = &self.best {
+                if (self.chosen.len(), literal_count) >= (*count, *literals) {
+                    return Ok(());
+                }
+            }
+
+            // Branch on the uncovered term with the fewest covering primes.
+            let next = (0..self.terms.len())
+                .filter(|&x| self.covered[x] == 0)
+                .min_by_key(|&x| self.covering[x].len());
+
+            let x = match next {
+                Some(x) => x,
+                None => {
+                    self.best = Some((self.chosen.len(), literal_count, self.chosen.clone()));
+                    return Ok(());
+                }
+            };
+
+            for y in self.covering[x].clone() {
+                self.select(y, true);
+                self.chosen.push(y);
+                self.search(literal_count + self.costs[y], timeout_signal)?;
+                self.chosen.pop();
+                self.select(y, false);
+            }
+
+            Ok(())
+        }
+
+        /// Updates the number of chosen primes covering each term.
+        fn select(&mut self, y: usize, is_selected: bool) {
+            for (x, term) in self.terms.iter().enumerate() {
+                if covers(&self.primes[y], term) {
+                    if is_selected {
+                        self.covered[x] += 1;
+                    } else {
+                        self.covered[x] -= 1;
+                    }
+                }
+            }
+        }
+    }
+
+    let mut search = Search {
+        covering: terms
+            .iter()
+            .map(|term| {
+                (0..primes.len())
+                    .filter(|&y| covers(&primes[y], term))
+                    .collect()
+            })
+            .collect(),
+        costs: primes
+            .iter()
+            .map(|prime| literal_count(prime, value_counts))
+            .collect(),
+        chosen: vec![],
+        best: None,
+        covered: vec![0; terms.len()],
+        primes,
+        terms,
+    };
+
+    search.search(0, timeout_signal)?;
+
+    let mut cover: Vec<Cube> = search
+        .best
+        .map(|(_, _, chosen)| chosen.into_iter().map(|y| primes[y].clone()).collect())
+        .unwrap_or_default();
+
+    cover.sort_unstable_by(|a, b| {
+        // Sort by the values of the variables in order, wildcards last.
+        let key = |cube: &Cube| {
+            cube.iter()
+                .zip(value_counts)
+                .map(|(&mask, &value_count)| {
+                    if mask == full_mask(value_count) {
+                        (1, 0)
+                    } else {
+                        (0, mask.trailing_zeros())
+                    }
+                })
+                .collect::<Vec<_>>()
+        };
+
+        key(a).cmp(&key(b)).then_with(|| a.cmp(b))
+    });
+
+    Ok(cover)
+}
+
+fn to_solution(
+    variables: &[MultiValuedVariable],
+    cover: &[Cube],
+    value_counts: &[usize],
+) -> MultiValuedSolution {
+    if cover.is_empty() {
+        return MultiValuedSolution::Zero;
+    }
+
+    let products = cover
+        .iter()
+        .map(|cube| {
+            cube.iter()
+                .zip(variables)
+                .zip(value_counts)
+                .filter(|&((&mask, _), &value_count)| mask != full_mask(value_count))
+                .map(|((&mask, variable), _)| SetLiteral {
+                    name: variable.name.clone(),
+                    values: (0..variable.values.len())
+                        .filter(|&value| mask >> value & 1 == 1)
+                        .map(|value| variable.values[value].clone())
+                        .collect(),
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    if products.iter().any(Vec::is_empty) {
+        MultiValuedSolution::One
+    } else {
+        MultiValuedSolution::SOP(products)
+    }
+}
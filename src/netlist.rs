@@ -0,0 +1,433 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::identifier::validate_ports;
+use crate::{Error, Form, Solution};
+
+/// A gate-level circuit of one or more [`Solution`]s sharing the same inputs.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["sel", "a", "b"];
+/// let solution = qmc::minimize(&variables, &[2, 3, 5, 7], &[0, 1, 4, 6], qmc::SOP, false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// let netlist = qmc::Netlist::new(&variables, &[("y", &solution)], qmc::Inverters::Shared).unwrap();
+///
+/// assert_eq!(
+///     netlist.gates,
+///     [
+///         qmc::Gate::new(qmc::GateKind::And, [qmc::Signal::Input(0), qmc::Signal::Input(2)]),
+///         qmc::Gate::new(qmc::GateKind::Not, [qmc::Signal::Input(0)]),
+///         qmc::Gate::new(qmc::GateKind::And, [qmc::Signal::Gate(1), qmc::Signal::Input(1)]),
+///         qmc::Gate::new(qmc::GateKind::Or, [qmc::Signal::Gate(0), qmc::Signal::Gate(2)]),
+///     ]
+/// );
+/// assert_eq!(netlist.outputs, [("y".to_owned(), qmc::Signal::Gate(3))]);
+/// assert!(netlist.to_dot("mux").contains("    g3 -> out0;\n"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Netlist {
+    pub inputs: Vec<String>,
+    /// The gates, each only driven by the inputs and the gates before it.
+    pub gates: Vec<Gate>,
+    /// The names of the outputs and the signals driving them.
+    pub outputs: Vec<(String, Signal)>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Gate {
+    pub kind: GateKind,
+    pub inputs: Vec<Signal>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum GateKind {
+    Not,
+    And,
+    Or,
+    Nand,
+    Nor,
+}
+
+/// A signal driving a gate input or an output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Signal {
+    /// The input at the index of [`Netlist::inputs`].
+    Input(usize),
+    /// The output of the gate at the index of [`Netlist::gates`].
+    Gate(usize),
+    Zero,
+    One,
+}
+
+/// How the negated inputs are generated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Inverters {
+    /// One inverter per input, shared between all terms and outputs.
+    Shared,
+    /// One inverter per negated literal.
+    PerLiteral,
+}
+
+impl Netlist {
+    /// Builds the two-level circuit of each of the `outputs`: an inverter for the negated inputs,
+    /// an AND gate for each product term and an OR gate combining them for [`SOP`](Form::SOP) solutions,
+    /// and the mirror of it for [`POS`](Form::POS) solutions.
+    ///
+    /// Terms with a single literal and expressions with a single term don't get a gate of their own.
+    ///
+    /// Returns [`Error::DuplicateVariables`] if a name is used more than once
+    /// and [`Error::UnknownVariable`] if a solution contains a variable that is not in `inputs`.
+    pub fn new<T: AsRef<str>, U: AsRef<str>>(
+        inputs: &[T],
+        outputs: &[(U, &Solution)],
+        inverters: Inverters,
+    ) -> Result<Self, Error> {
+        let inputs = inputs.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let output_names = outputs
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>();
+
+        validate_ports(
+            &inputs,
+            &output_names,
+            outputs.iter().map(|(_, solution)| *solution),
+        )?;
+
+        let mut netlist = Netlist {
+            inputs: inputs.iter().map(|&input| input.to_owned()).collect(),
+            gates: vec![],
+            outputs: vec![],
+        };
+        let mut shared_inverters = HashMap::new();
+
+        for (name, (_, solution)) in output_names.into_iter().zip(outputs) {
+            let (expression, form) = match solution.expression() {
+                Some(expression) => expression,
+                None => {
+                    let constant = if matches!(solution, Solution::One) {
+                        Signal::One
+                    } else {
+                        Signal::Zero
+                    };
+
+                    netlist.outputs.push((name.to_owned(), constant));
+                    continue;
+                }
+            };
+
+            let (inner_kind, outer_kind) = if form == Form::SOP {
+                (GateKind::And, GateKind::Or)
+            } else {
+                (GateKind::Or, GateKind::And)
+            };
+
+            let mut terms = vec![];
+
+            for variables in expression {
+                let mut literals = vec![];
+
+                for variable in variables {
+                    let input = Signal::Input(
+                        inputs
+                            .iter()
+                            .position(|&input| input == variable.name)
+                            .unwrap(),
+                    );
+
+                    let literal = match (variable.is_negated, inverters) {
+                        (false, _) => input,
+                        (true, Inverters::Shared) => match shared_inverters.get(&input) {
+                            Some(&inverter) => inverter,
+                            None => {
+                                let inverter = netlist.add_gate(GateKind::Not, vec![input]);
+                                shared_inverters.insert(input, inverter);
+                                inverter
+                            }
+                        },
+                        (true, Inverters::PerLiteral) => {
+                            netlist.add_gate(GateKind::Not, vec![input])
+                        }
+                    };
+
+                    literals.push(literal);
+                }
+
+                terms.push(if literals.len() == 1 {
+                    literals[0]
+                } else {
+                    netlist.add_gate(inner_kind, literals)
+                });
+            }
+
+            let signal = if terms.len() == 1 {
+                terms[0]
+            } else {
+                netlist.add_gate(outer_kind, terms)
+            };
+
+            netlist.outputs.push((name.to_owned(), signal));
+        }
+
+        Ok(netlist)
+    }
+
+    /// Renders the circuit as a Graphviz DOT graph laid out from left to right,
+    /// with the inputs and outputs as plain text nodes and the gates as boxes.
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut dot = format!("digraph {} {{\n    rankdir=LR;\n\n", quote(name));
+        let mut edges = vec![];
+
+        for (i, input) in self.inputs.iter().enumerate() {
+            writeln!(dot, "    in{} [label={}, shape=none];", i, quote(input)).unwrap();
+        }
+
+        let uses_constant = |constant| {
+            self.gates
+                .iter()
+                .flat_map(|gate| &gate.inputs)
+                .chain(self.outputs.iter().map(|(_, signal)| signal))
+                .any(|&signal| signal == constant)
+        };
+
+        if uses_constant(Signal::Zero) {
+            dot.push_str("    zero [label=\"0\", shape=none];\n");
+        }
+
+        if uses_constant(Signal::One) {
+            dot.push_str("    one [label=\"1\", shape=none];\n");
+        }
+
+        for (i, gate) in self.gates.iter().enumerate() {
+            writeln!(
+                dot,
+                "    g{} [label=\"{}\", shape=box];",
+                i,
+                gate.kind.name()
+            )
+            .unwrap();
+
+            for &signal in &gate.inputs {
+                edges.push(format!("{} -> g{};", node(signal), i));
+            }
+        }
+
+        for (i, (output, signal)) in self.outputs.iter().enumerate() {
+            writeln!(dot, "    out{} [label={}, shape=none];", i, quote(output)).unwrap();
+            edges.push(format!("{} -> out{};", node(*signal), i));
+        }
+
+        dot.push('\n');
+
+        for edge in &edges {
+            writeln!(dot, "    {}", edge).unwrap();
+        }
+
+        dot.push_str("}\n");
+
+        dot
+    }
+
+    /// Converts the circuit to one of only NAND gates, an inverter being a NAND gate with a single input.
+    ///
+    /// Inversions are pushed through the gates instead of adding inverters wherever possible,
+    /// so the two-level circuit of an [`SOP`](Form::SOP) solution becomes a NAND–NAND network
+    /// with the same number of gates. Other circuits, e.g. that of a [`POS`](Form::POS) solution,
+    /// get inverters where the polarity of a signal can't be absorbed by the gates around it.
+    ///
+    /// Gates not driving any output are dropped.
+    pub fn to_nand(&self) -> Netlist {
+        self.to_universal(GateKind::Nand)
+    }
+
+    /// Converts the circuit to one of only NOR gates, an inverter being a NOR gate with a single input.
+    ///
+    /// This is the mirror of [`Netlist::to_nand`], so the two-level circuit of a [`POS`](Form::POS) solution
+    /// becomes a NOR–NOR network with the same number of gates.
+    pub fn to_nor(&self) -> Netlist {
+        self.to_universal(GateKind::Nor)
+    }
+
+    pub fn gate_count(&self) -> usize {
+        self.gates.len()
+    }
+
+    /// Returns the number of gates of the given kind.
+    pub fn gate_count_of(&self, kind: GateKind) -> usize {
+        self.gates.iter().filter(|gate| gate.kind == kind).count()
+    }
+
+    /// Returns the largest number of gates on a path from an input to an output, inverters included.
+    pub fn depth(&self) -> usize {
+        let mut depths = Vec::with_capacity(self.gates.len());
+        let depth = |depths: &[usize], signal: &Signal| match signal {
+            Signal::Gate(i) => depths[*i],
+            _ => 0,
+        };
+
+        for gate in &self.gates {
+            let gate_depth = gate
+                .inputs
+                .iter()
+                .map(|signal| depth(&depths, signal))
+                .max()
+                .unwrap_or(0);
+
+            depths.push(gate_depth + 1);
+        }
+
+        self.outputs
+            .iter()
+            .map(|(_, signal)| depth(&depths, signal))
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn to_universal(&self, kind: GateKind) -> Netlist {
+        let mut converter = Converter {
+            source: self,
+            kind,
+            netlist: Netlist {
+                inputs: self.inputs.clone(),
+                gates: vec![],
+                outputs: vec![],
+            },
+            signals: HashMap::new(),
+        };
+
+        for (name, signal) in &self.outputs {
+            let signal = converter.signal(*signal, false);
+            converter.netlist.outputs.push((name.clone(), signal));
+        }
+
+        converter.netlist
+    }
+
+    fn add_gate(&mut self, kind: GateKind, inputs: Vec<Signal>) -> Signal {
+        self.gates.push(Gate { kind, inputs });
+        Signal::Gate(self.gates.len() - 1)
+    }
+}
+
+impl Gate {
+    pub fn new(kind: GateKind, inputs: impl IntoIterator<Item = Signal>) -> Self {
+        Gate {
+            kind,
+            inputs: inputs.into_iter().collect(),
+        }
+    }
+}
+
+impl GateKind {
+    /// The name of the gate in upper case, e.g. `NAND`.
+    pub fn name(self) -> &'static str {
+        match self {
+            GateKind::Not => "NOT",
+            GateKind::And => "AND",
+            GateKind::Or => "OR",
+            GateKind::Nand => "NAND",
+            GateKind::Nor => "NOR",
+        }
+    }
+}
+
+/// Converts a netlist to one of only NAND or NOR gates.
+struct Converter<'a> {
+    source: &'a Netlist,
+    kind: GateKind,
+    netlist: Netlist,
+    /// The converted signals of the source signals and their complements.
+    signals: HashMap<(Signal, bool), Signal>,
+}
+
+impl Converter<'_> {
+    /// Returns the converted signal of the source `signal`, or of its complement if `is_negated`,
+    /// adding the gates it needs.
+    fn signal(&mut self, signal: Signal, is_negated: bool) -> Signal {
+        if let Some(&converted) = self.signals.get(&(signal, is_negated)) {
+            return converted;
+        }
+
+        let converted = match signal {
+            Signal::Zero if is_negated => Signal::One,
+            Signal::One if is_negated => Signal::Zero,
+            Signal::Zero | Signal::One => signal,
+            Signal::Input(_) if !is_negated => signal,
+            Signal::Input(_) => self.inverter(signal, is_negated),
+            Signal::Gate(i) => {
+                let source = self.source;
+                let gate = &source.gates[i];
+
+                // Whether the universal gate computes the complement of the gate
+                // and whether it takes the complements of the inputs to do so.
+                let (is_output_negated, are_inputs_negated) = match (gate.kind, self.kind) {
+                    (GateKind::Not, _) => {
+                        let converted = self.signal(gate.inputs[0], !is_negated);
+                        self.signals.insert((signal, is_negated), converted);
+                        return converted;
+                    }
+                    (GateKind::And, GateKind::Nand) | (GateKind::Or, GateKind::Nor) => {
+                        (true, false)
+                    }
+                    (GateKind::Or, GateKind::Nand) | (GateKind::And, GateKind::Nor) => {
+                        (false, true)
+                    }
+                    (GateKind::Nor, GateKind::Nand) | (GateKind::Nand, GateKind::Nor) => {
+                        (true, true)
+                    }
+                    _ => (false, false),
+                };
+
+                if is_output_negated == is_negated {
+                    let inputs = gate
+                        .inputs
+                        .iter()
+                        .map(|&input| self.signal(input, are_inputs_negated))
+                        .collect();
+
+                    self.netlist.add_gate(self.kind, inputs)
+                } else {
+                    self.inverter(signal, is_negated)
+                }
+            }
+        };
+
+        self.signals.insert((signal, is_negated), converted);
+
+        converted
+    }
+
+    /// Adds an inverter of the opposite polarity of the source `signal`.
+    fn inverter(&mut self, signal: Signal, is_negated: bool) -> Signal {
+        let input = self.signal(signal, !is_negated);
+        self.netlist.add_gate(self.kind, vec![input])
+    }
+}
+
+fn node(signal: Signal) -> String {
+    match signal {
+        Signal::Input(i) => format!("in{}", i),
+        Signal::Gate(i) => format!("g{}", i),
+        Signal::Zero => "zero".into(),
+        Signal::One => "one".into(),
+    }
+}
+
+pub(crate) fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
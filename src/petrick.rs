@@ -1,5 +1,6 @@
+use crate::progress::TProgress;
 use crate::timeout_signal::TTimeoutSignal;
-use crate::{implicant::Implicant, prime_implicant_chart::PrimeImplicantChart, Error};
+use crate::{implicant::Implicant, prime_implicant_chart::PrimeImplicantChart, Error, Progress};
 
 pub struct Petrick;
 
@@ -7,6 +8,7 @@ impl Petrick {
     pub fn solve(
         prime_implicant_chart: &PrimeImplicantChart,
         timeout_signal: &impl TTimeoutSignal,
+        progress: &impl TProgress,
     ) -> Result<Vec<Vec<Implicant>>, Error> {
         let mut sums: Vec<SumOfProduct> = prime_implicant_chart
             .get_column_covering_implicants()
@@ -19,6 +21,11 @@ impl Petrick {
         }
 
         while sums.len() > 1 && timeout_signal.is_not_signaled() {
+            progress.report(Progress::PetrickDistribution {
+                sums: sums.len(),
+                products: sums.iter().fold(0, |acc, sum| acc + sum.products.len()),
+            });
+
             #[cfg(test)]
             println!(
                 "Distributing {} sums ({} products)...",
@@ -54,7 +61,7 @@ impl Petrick {
         let mut distributed_sums = Vec::with_capacity((sums.len() + (CHUNK_SIZE - 1)) / CHUNK_SIZE);
 
         for adjacent_sums in sums.chunks_exact(CHUNK_SIZE) {
-            if timeout_signal.is_signaled() {
+            if timeout_signal.is_signaled_now() {
                 return Err(Error::Timeout);
             }
 
@@ -134,7 +141,7 @@ impl SumOfProduct {
             Vec::with_capacity(self.products.len() * other.products.len());
 
         for product in &self.products {
-            if timeout_signal.is_signaled() {
+            if timeout_signal.is_signaled_now() {
                 return Err(Error::Timeout);
             }
 
@@ -154,7 +161,7 @@ impl SumOfProduct {
 
     pub fn absorb(&mut self, timeout_signal: &impl TTimeoutSignal) -> Result<(), Error> {
         for i in (0..self.products.len()).rev() {
-            if timeout_signal.is_signaled() {
+            if timeout_signal.is_signaled_now() {
                 return Err(Error::Timeout);
             }
 
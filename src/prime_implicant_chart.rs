@@ -1,20 +1,35 @@
 // See the paper "Minimization of Boolean expressions using matrix algebra"
 
 use crate::implicant::Implicant;
+use crate::progress::TProgress;
 use crate::timeout_signal::TTimeoutSignal;
-use crate::Error;
+use crate::{Error, Progress};
 use std::collections::{HashMap, HashSet};
 
+#[derive(Clone)]
 pub struct PrimeImplicantChart {
     implicants: Vec<Implicant>,
     rows: Vec<Vec<bool>>,
-    terms: Vec<u32>,
+    /// The care terms as cubes without wildcards, followed by the required cubes.
+    terms: Vec<Implicant>,
     cols: Vec<Vec<bool>>,
     essential_prime_implicants: Vec<Implicant>,
+    dominated_implicants: Vec<Implicant>,
+    dominating_terms: Vec<Implicant>,
 }
 
 impl PrimeImplicantChart {
     pub fn new(implicants: Vec<Implicant>, dont_cares: &HashSet<u32>) -> Self {
+        Self::with_required_cubes(implicants, dont_cares, &[])
+    }
+
+    /// Creates the chart with an additional column for each of the `required_cubes`,
+    /// which has to be covered by a single implicant.
+    pub fn with_required_cubes(
+        implicants: Vec<Implicant>,
+        dont_cares: &HashSet<u32>,
+        required_cubes: &[Implicant],
+    ) -> Self {
         let mut terms = HashSet::new();
 
         for implicant in &implicants {
@@ -23,13 +38,22 @@ impl PrimeImplicantChart {
 
         terms = terms.difference(dont_cares).copied().collect();
 
+        let mut terms = terms.into_iter().map(Implicant::new).collect::<Vec<_>>();
+        let term_count = terms.len();
+
+        for &cube in required_cubes {
+            if cube.wildcard_count() > 0 && !terms.contains(&cube) {
+                terms.push(cube);
+            }
+        }
+
         let mut rows = vec![vec![false; terms.len()]; implicants.len()];
         let mut cols = vec![vec![false; implicants.len()]; terms.len()];
 
-        let term_indices: HashMap<u32, usize> = terms
+        let term_indices: HashMap<u32, usize> = terms[..term_count]
             .iter()
             .enumerate()
-            .map(|(i, &term)| (term, i))
+            .map(|(i, term)| (term.value(), i))
             .collect();
 
         for (y, implicant) in implicants.iter().enumerate() {
@@ -41,14 +65,23 @@ impl PrimeImplicantChart {
                 rows[y][x] = true;
                 cols[x][y] = true;
             }
+
+            for (x, cube) in terms.iter().enumerate().skip(term_count) {
+                if implicant.covers(*cube) {
+                    rows[y][x] = true;
+                    cols[x][y] = true;
+                }
+            }
         }
 
         PrimeImplicantChart {
             implicants,
             rows,
-            terms: Vec::from_iter(terms),
+            terms,
             cols,
             essential_prime_implicants: vec![],
+            dominated_implicants: vec![],
+            dominating_terms: vec![],
         }
     }
 
@@ -56,6 +89,7 @@ impl PrimeImplicantChart {
         &mut self,
         only_extract: bool,
         timeout_signal: &impl TTimeoutSignal,
+        progress: &impl TProgress,
     ) -> Result<Vec<Implicant>, Error> {
         #[cfg(test)]
         println!(
@@ -72,6 +106,12 @@ impl PrimeImplicantChart {
         }
 
         while timeout_signal.is_not_signaled() {
+            progress.report(Progress::ChartReduction {
+                implicants: self.implicants.len(),
+                terms: self.terms.len(),
+                essential_prime_implicants: self.essential_prime_implicants.len(),
+            });
+
             let any_essentials_extracted = self.extract_essential_prime_implicants();
             let any_terms_removed = self.remove_dominating_terms(timeout_signal)?;
             let any_implicants_removed = self.remove_dominated_implicants(timeout_signal)?;
@@ -88,6 +128,66 @@ impl PrimeImplicantChart {
         }
     }
 
+    /// Adds a row for the implicant, marking the columns it covers.
+    pub fn insert_implicant(&mut self, implicant: Implicant) {
+        let row = self
+            .terms
+            .iter()
+            .map(|&term| implicant.covers(term))
+            .collect::<Vec<_>>();
+
+        for (col, &is_marked) in self.cols.iter_mut().zip(&row) {
+            col.push(is_marked);
+        }
+
+        self.rows.push(row);
+        self.implicants.push(implicant);
+    }
+
+    /// Removes the row of the implicant if there is one.
+    pub fn remove_implicant(&mut self, implicant: Implicant) {
+        if let Some(y) = self.implicants.iter().position(|&other| other == implicant) {
+            self.remove_row(y);
+        }
+    }
+
+    /// Adds a column for the care term after the other care terms, marking the implicants covering it.
+    pub fn insert_term(&mut self, term: u32) {
+        let term = Implicant::new(term);
+        let x = self
+            .terms
+            .iter()
+            .position(|term| term.wildcard_count() > 0)
+            .unwrap_or(self.terms.len());
+        let col = self
+            .implicants
+            .iter()
+            .map(|implicant| implicant.covers(term))
+            .collect::<Vec<_>>();
+
+        for (row, &is_marked) in self.rows.iter_mut().zip(&col) {
+            row.insert(x, is_marked);
+        }
+
+        self.cols.insert(x, col);
+        self.terms.insert(x, term);
+    }
+
+    /// Removes the column of the care term if there is one, keeping the order of the others.
+    pub fn remove_term(&mut self, term: u32) {
+        let term = Implicant::new(term);
+
+        if let Some(x) = self.terms.iter().position(|&other| other == term) {
+            self.cols.remove(x);
+
+            for row in &mut self.rows {
+                row.remove(x);
+            }
+
+            self.terms.remove(x);
+        }
+    }
+
     pub fn get_column_covering_implicants(&self) -> Vec<Vec<Implicant>> {
         let mut column_covering_implicants = Vec::with_capacity(self.terms.len());
 
@@ -110,6 +210,30 @@ impl PrimeImplicantChart {
         column_covering_implicants
     }
 
+    pub fn implicants(&self) -> &[Implicant] {
+        &self.implicants
+    }
+
+    pub fn terms(&self) -> &[Implicant] {
+        &self.terms
+    }
+
+    pub fn is_marked(&self, y: usize, x: usize) -> bool {
+        self.rows[y][x]
+    }
+
+    pub fn essential_prime_implicants(&self) -> &[Implicant] {
+        &self.essential_prime_implicants
+    }
+
+    pub fn dominated_implicants(&self) -> &[Implicant] {
+        &self.dominated_implicants
+    }
+
+    pub fn dominating_terms(&self) -> &[Implicant] {
+        &self.dominating_terms
+    }
+
     fn extract_essential_prime_implicants(&mut self) -> bool {
         let mut rows_to_extract = HashSet::new();
         let mut covered_columns = HashSet::new();
@@ -185,7 +309,8 @@ impl PrimeImplicantChart {
                 }
 
                 if is_dominating(&self.cols[x1], &self.cols[x2]) {
-                    self.remove_col(x1);
+                    let term = self.remove_col(x1);
+                    self.dominating_terms.push(term);
                     removed = true;
                     #[cfg(test)]
                     {
@@ -224,7 +349,8 @@ impl PrimeImplicantChart {
                     // Only remove if it has more or an equal number of literals.
                     && self.implicants[y1].wildcard_count() <= self.implicants[y2].wildcard_count()
                 {
-                    self.remove_row(y1);
+                    let implicant = self.remove_row(y1);
+                    self.dominated_implicants.push(implicant);
                     removed = true;
                     #[cfg(test)]
                     {
@@ -245,6 +371,7 @@ impl PrimeImplicantChart {
         }
     }
 
+    #[allow(clippy::unnecessary_sort_by)]
     fn sort(&mut self) {
         // Sort implicants to make the simplification deterministic.
         let mut sorted_implicants: Vec<_> = self.implicants.iter().zip(self.rows.clone()).collect();
@@ -285,7 +412,7 @@ impl PrimeImplicantChart {
         self.implicants.swap_remove(y)
     }
 
-    fn remove_col(&mut self, x: usize) -> u32 {
+    fn remove_col(&mut self, x: usize) -> Implicant {
         self.cols.swap_remove(x);
 
         for row in &mut self.rows {
@@ -0,0 +1,46 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// A step of a minimization, passed to the callback set with [`Minimizer::progress`](crate::Minimizer::progress).
+///
+/// The steps come in the order of the variants, each but the first one possibly repeated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Progress {
+    /// The terms and don't cares are grouped by their number of ones (zeros in POS form).
+    Grouping { terms: usize },
+    /// A round of combining adjacent groups, numbered from 1, with the number of implicants in the groups
+    /// and the number of prime implicants found in the rounds before.
+    Combining {
+        round: usize,
+        implicants: usize,
+        prime_implicants: usize,
+    },
+    /// A round of reducing the prime implicant chart by extracting essential prime implicants
+    /// and removing dominating terms and dominated implicants, with what is left of the chart before it.
+    ChartReduction {
+        implicants: usize,
+        terms: usize,
+        essential_prime_implicants: usize,
+    },
+    /// A round of Petrick's method distributing pairs of sums of products.
+    PetrickDistribution { sums: usize, products: usize },
+}
+
+/// Receives the [`Progress`] of a minimization, separately from its timeout signal.
+pub trait TProgress {
+    fn report(&self, progress: Progress);
+}
+
+#[derive(Default)]
+pub struct ProgressNoOp;
+
+impl TProgress for ProgressNoOp {
+    fn report(&self, _progress: Progress) {}
+}
+
+impl<F: Fn(&Progress)> TProgress for F {
+    fn report(&self, progress: Progress) {
+        self(&progress);
+    }
+}
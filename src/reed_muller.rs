@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use crate::esop::Esop;
+use crate::implicant::Implicant;
+use crate::{own_variables, validate_input, Error};
+
+/// Computes the positive-polarity Reed–Muller expansion, also known as the algebraic normal form (ANF),
+/// of the fully specified boolean function that is 1 exactly for the given `minterms`.
+///
+/// The expansion is an exclusive-or of products of uncomplemented variables and is unique for every function,
+/// so it can be used as a canonical form to compare functions.
+///
+/// Returns an [`Error`] for the same bad input as [`minimize_minterms`](crate::minimize_minterms).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let anf = qmc::anf(&qmc::DEFAULT_VARIABLES[..2], &[1, 2, 3]).unwrap();
+///
+/// assert_eq!(anf.to_string(), "A ⊕ B ⊕ (A ∧ B)");
+/// ```
+pub fn anf<T: AsRef<str>>(variables: &[T], minterms: &[u32]) -> Result<Esop, Error> {
+    reed_muller(variables, minterms, 0)
+}
+
+/// Computes the fixed-polarity Reed–Muller expansion of the fully specified boolean function
+/// that is 1 exactly for the given `minterms`.
+///
+/// Every variable appears either only uncomplemented or only complemented in the expansion,
+/// the latter if its bit is set in `polarity`, the first variable being the most significant bit.
+/// Bits beyond the number of variables are ignored.
+///
+/// Returns an [`Error`] for the same bad input as [`minimize_minterms`](crate::minimize_minterms).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let expansion = qmc::reed_muller(&qmc::DEFAULT_VARIABLES[..2], &[1, 2, 3], 0b11).unwrap();
+///
+/// assert_eq!(expansion.to_string(), "1 ⊕ (~A ∧ ~B)");
+/// ```
+pub fn reed_muller<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    polarity: u32,
+) -> Result<Esop, Error> {
+    let (variables, minterms) = validate(variables, minterms)?;
+    let polarity = polarity & mask(variables.len());
+    let cubes = expansion(variables.len(), |term| minterms.contains(&term), polarity);
+
+    Ok(Esop::new(variables, cubes))
+}
+
+/// Computes the fixed-polarity Reed–Muller expansion with the fewest products,
+/// and among those the fewest literals, of the fully specified boolean function
+/// that is 1 exactly for the given `minterms`. Returns the expansion and its polarity
+/// as described in [`reed_muller`].
+///
+/// All `2^n` polarities are tried, so the running time grows with `4^n` for `n` variables.
+///
+/// Returns an [`Error`] for the same bad input as [`minimize_minterms`](crate::minimize_minterms).
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = &qmc::DEFAULT_VARIABLES[..3];
+/// let minterms = [1, 2, 3, 4, 5, 6, 7];
+/// let (expansion, polarity) = qmc::minimize_reed_muller(variables, &minterms).unwrap();
+///
+/// assert_eq!(qmc::anf(variables, &minterms).unwrap().product_count(), 7);
+/// assert_eq!(expansion.to_string(), "1 ⊕ (~A ∧ ~B ∧ ~C)");
+/// assert_eq!(polarity, 0b111);
+/// ```
+pub fn minimize_reed_muller<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+) -> Result<(Esop, u32), Error> {
+    let (variables, minterms) = validate(variables, minterms)?;
+    let variable_count = variables.len();
+    let cost = |coefficients: &[bool]| {
+        let (mut product_count, mut literal_count) = (0, 0);
+
+        for (monomial, _) in coefficients.iter().enumerate().filter(|(_, &c)| c) {
+            product_count += 1;
+            literal_count += monomial.count_ones();
+        }
+
+        (product_count, literal_count)
+    };
+
+    let mut polarity = 0;
+    let mut coefficients = transform(variable_count, |term| minterms.contains(&term), polarity);
+    let mut best = (cost(&coefficients), polarity);
+
+    // Visiting the polarities in Gray code order changes the polarity of one variable at a time,
+    // which takes a single pass over the coefficients.
+    for i in 1..1u32 << variable_count {
+        let bit = i.trailing_zeros();
+
+        polarity ^= 1 << bit;
+        complement(&mut coefficients, bit);
+
+        let cost = cost(&coefficients);
+
+        if cost < best.0 {
+            best = (cost, polarity);
+        }
+    }
+
+    let polarity = best.1;
+    let cubes = expansion(variable_count, |term| minterms.contains(&term), polarity);
+
+    Ok((Esop::new(variables, cubes), polarity))
+}
+
+/// Computes the fixed-polarity Reed–Muller expansion of the function, as products of literals
+/// that are complemented for the variables whose bits are set in `polarity`.
+pub(crate) fn expansion(
+    variable_count: usize,
+    value: impl Fn(u32) -> bool,
+    polarity: u32,
+) -> Vec<Implicant> {
+    let all = mask(variable_count);
+
+    transform(variable_count, value, polarity)
+        .into_iter()
+        .enumerate()
+        .filter(|&(_, coefficient)| coefficient)
+        .map(|(monomial, _)| {
+            let monomial = monomial as u32;
+
+            Implicant::from_parts(!polarity & monomial, all & !monomial)
+        })
+        .collect()
+}
+
+/// Returns the coefficients of the monomials of the expansion, indexed by the bits of their variables.
+fn transform(variable_count: usize, value: impl Fn(u32) -> bool, polarity: u32) -> Vec<bool> {
+    // Substituting the complemented variables turns the expansion into a positive-polarity one.
+    let mut coefficients = (0..=mask(variable_count))
+        .map(|term| value(term ^ polarity))
+        .collect::<Vec<_>>();
+
+    for bit in 0..variable_count as u32 {
+        butterfly(&mut coefficients, bit);
+    }
+
+    coefficients
+}
+
+/// Expands the function in the variable of `bit` as `f = g ⊕ x·h`: the monomials containing it
+/// get the coefficients of `h = f(x = 0) ⊕ f(x = 1)`, the others keep those of `g = f(x = 0)`.
+fn butterfly(coefficients: &mut [bool], bit: u32) {
+    let bit = 1 << bit;
+
+    for term in 0..coefficients.len() {
+        if term & bit != 0 {
+            coefficients[term] ^= coefficients[term ^ bit];
+        }
+    }
+}
+
+/// Changes the polarity of the variable of `bit` in an expansion, since `g ⊕ x·h = (g ⊕ h) ⊕ ~x·h`.
+fn complement(coefficients: &mut [bool], bit: u32) {
+    let bit = 1 << bit;
+
+    for term in 0..coefficients.len() {
+        if term & bit == 0 {
+            coefficients[term] ^= coefficients[term | bit];
+        }
+    }
+}
+
+fn mask(variable_count: usize) -> u32 {
+    (1 << variable_count) - 1
+}
+
+fn validate<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+) -> Result<(Vec<String>, HashSet<u32>), Error> {
+    let variables = own_variables(variables);
+    let minterms = minterms.iter().copied().collect::<HashSet<_>>();
+
+    validate_input(&variables, &minterms, &HashSet::new())?;
+
+    Ok((variables, minterms))
+}
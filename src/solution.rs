@@ -1,9 +1,14 @@
+use std::collections::HashSet;
 use std::fmt::Display;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{implicant::Implicant, Form};
+use crate::bdd::Bdd;
+use crate::cost::{self, CostMetric};
+use crate::factor::{self, Expression};
+use crate::verify::{self, Verification};
+use crate::{implicant::Implicant, minimize, validate_input, Error, Form, Style};
 
 /// A minimized boolean expression.
 #[derive(Debug, Clone)]
@@ -48,40 +53,231 @@ impl Solution {
             Solution::POS(expression)
         }
     }
-}
 
-impl Display for Solution {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let (expression, form) = match self {
-            Solution::One => return write!(f, "1"),
-            Solution::Zero => return write!(f, "0"),
-            Solution::SOP(expression) => (expression, Form::SOP),
-            Solution::POS(expression) => (expression, Form::POS),
+    pub(crate) fn expression(&self) -> Option<(&Vec<Vec<Variable>>, Form)> {
+        match self {
+            Solution::One | Solution::Zero => None,
+            Solution::SOP(expression) => Some((expression, Form::SOP)),
+            Solution::POS(expression) => Some((expression, Form::POS)),
+        }
+    }
+
+    pub(crate) fn rename_variables(&self, rename: impl Fn(&str) -> String) -> Self {
+        let rename_expression = |expression: &Vec<Vec<Variable>>| {
+            expression
+                .iter()
+                .map(|variables| {
+                    variables
+                        .iter()
+                        .map(|variable| Variable::new(rename(&variable.name), variable.is_negated))
+                        .collect()
+                })
+                .collect()
         };
 
-        for (i, variables) in expression.iter().enumerate() {
-            if expression.len() > 1 && variables.len() > 1 {
-                write!(f, "(")?;
-            }
+        match self {
+            Solution::One => Solution::One,
+            Solution::Zero => Solution::Zero,
+            Solution::SOP(expression) => Solution::SOP(rename_expression(expression)),
+            Solution::POS(expression) => Solution::POS(rename_expression(expression)),
+        }
+    }
 
-            for (j, variable) in variables.iter().enumerate() {
-                write!(f, "{}", variable)?;
+    /// Factors the two-level expression into a multi-level [`Expression`] with algebraic division,
+    /// e.g. `(A ∧ B ∧ C) ∨ (A ∧ B ∧ D)` into `A ∧ B ∧ (C ∨ D)`.
+    ///
+    /// The expression is repeatedly divided by the kernel, a cube-free quotient of it by a product term,
+    /// that saves the most literals. [`Solution::POS`] is factored the same way with the roles
+    /// of the operators swapped. Compare [`Expression::literal_count`] with [`Solution::literal_count`]
+    /// to see how many literals are saved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::minimize_minterms(&qmc::DEFAULT_VARIABLES[..4], &[14, 13], &[], false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    /// let factored = solution.factor();
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∧ B ∧ C ∧ ~D) ∨ (A ∧ B ∧ ~C ∧ D)");
+    /// assert_eq!(factored.to_string(), "A ∧ B ∧ ((C ∧ ~D) ∨ (~C ∧ D))");
+    /// assert_eq!((solution.literal_count(), factored.literal_count()), (8, 6));
+    /// ```
+    pub fn factor(&self) -> Expression {
+        factor::factor(self)
+    }
 
-                if j < variables.len() - 1 {
-                    write!(f, " {} ", if form == Form::SOP { "∧" } else { "∨" })?;
-                }
-            }
+    /// Checks whether the solution represents the same function as `other`, e.g. a hand-written expression,
+    /// returning an input assignment of the variables of both solutions for which they differ if not.
+    ///
+    /// The solutions are compared with binary decision diagrams, so the number of variables isn't limited.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::minimize_minterms(&["a", "b"], &[1, 2, 3], &[], false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    /// let a = qmc::Solution::SOP(vec![vec![qmc::Variable { name: "a".into(), is_negated: false }]]);
+    ///
+    /// match solution.is_equivalent(&a) {
+    ///     qmc::Verification::Counterexample(counterexample) => {
+    ///         assert_eq!(counterexample.to_string(), "a = 0, b = 1 gives 1");
+    ///     }
+    ///     qmc::Verification::Equivalent => unreachable!(),
+    /// }
+    /// ```
+    pub fn is_equivalent(&self, other: &Solution) -> Verification {
+        verify::is_equivalent(self, other)
+    }
 
-            if expression.len() > 1 && variables.len() > 1 {
-                write!(f, ")")?;
-            }
+    /// Checks whether the solution is 1 for all `minterms` and 0 for all `maxterms` of the `variables`,
+    /// returning the first term for which it isn't as a counterexample.
+    ///
+    /// Returns [`Error::UnknownVariable`] if the solution contains a variable that is not in `variables`,
+    /// and an [`Error`] for the same bad input as [`minimize`](crate::minimize).
+    pub fn verify_against<T: AsRef<str>>(
+        &self,
+        variables: &[T],
+        minterms: &[u32],
+        maxterms: &[u32],
+    ) -> Result<Verification, Error> {
+        verify::verify_against(self, variables, minterms, maxterms)
+    }
+
+    /// Returns a minimized expression of the negated function in the given form.
+    ///
+    /// In the opposite form it is derived with De Morgan's laws, which turn a minimal expression
+    /// into a minimal expression of the negated function. In the same form the negated function is minimized
+    /// over the variables of the solution. The don't cares the solution was minimized with aren't known anymore,
+    /// so they are treated as the values the solution has for them.
+    ///
+    /// Returns an [`Error`] for the same bad variables as [`minimize`] if minimizing.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use quine_mccluskey as qmc;
+    ///
+    /// let solution = qmc::minimize_minterms(&qmc::DEFAULT_VARIABLES[..3], &[1, 3, 6, 7], &[], false, None)
+    ///     .unwrap()
+    ///     .pop()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(solution.to_string(), "(A ∧ B) ∨ (~A ∧ C)");
+    /// assert_eq!(solution.complement(qmc::POS).unwrap().to_string(), "(~A ∨ ~B) ∧ (A ∨ ~C)");
+    /// assert_eq!(solution.complement(qmc::SOP).unwrap().to_string(), "(A ∧ ~B) ∨ (~A ∧ ~C)");
+    /// ```
+    pub fn complement(&self, form: Form) -> Result<Solution, Error> {
+        let (expression, own_form) = match self.expression() {
+            Some(expression) => expression,
+            None if matches!(self, Solution::One) => return Ok(Solution::Zero),
+            None => return Ok(Solution::One),
+        };
+
+        if form != own_form {
+            let negated = expression
+                .iter()
+                .map(|variables| {
+                    variables
+                        .iter()
+                        .map(|variable| Variable::new(variable.name.clone(), !variable.is_negated))
+                        .collect()
+                })
+                .collect();
 
-            if i < expression.len() - 1 {
-                write!(f, " {} ", if form == Form::SOP { "∨" } else { "∧" })?;
+            return Ok(match form {
+                Form::SOP => Solution::SOP(negated),
+                Form::POS => Solution::POS(negated),
+            });
+        }
+
+        let variables = self.variable_order();
+
+        validate_input(&variables, &HashSet::new(), &HashSet::new())?;
+
+        let bdd = Bdd::from_solution_unchecked(variables.clone(), self)?;
+        let (ones, zeros): (Vec<u32>, Vec<u32>) =
+            (0..1 << variables.len()).partition(|&term| bdd.evaluate(term));
+
+        Ok(minimize(&variables, &zeros, &ones, form, false, None)?
+            .pop()
+            .unwrap())
+    }
+
+    /// Returns the dual expression, with conjunctions and disjunctions as well as 0 and 1 swapped.
+    pub fn dual(&self) -> Solution {
+        match self {
+            Solution::One => Solution::Zero,
+            Solution::Zero => Solution::One,
+            Solution::SOP(expression) => Solution::POS(expression.clone()),
+            Solution::POS(expression) => Solution::SOP(expression.clone()),
+        }
+    }
+
+    /// Returns the variables of the expression in an order consistent with the order within its terms,
+    /// which is that of the variables the solution was minimized with.
+    fn variable_order(&self) -> Vec<String> {
+        let terms = self
+            .expression()
+            .map_or(&[][..], |(expression, _)| expression);
+        let mut names: Vec<&str> = vec![];
+
+        for variable in terms.iter().flatten() {
+            if !names.contains(&variable.name.as_str()) {
+                names.push(&variable.name);
             }
         }
 
-        Ok(())
+        let precedes = |a: &str, b: &str| {
+            terms.iter().any(|term| {
+                let position = |name| term.iter().position(|variable| variable.name == name);
+
+                matches!((position(a), position(b)), (Some(a), Some(b)) if a < b)
+            })
+        };
+        let mut ordered = vec![];
+
+        while !names.is_empty() {
+            // The first variable no other remaining one has to precede, or the first one if the terms disagree.
+            let next = (0..names.len())
+                .find(|&i| !names.iter().any(|other| precedes(other, names[i])))
+                .unwrap_or(0);
+
+            ordered.push(names.remove(next).to_owned());
+        }
+
+        ordered
+    }
+
+    /// Returns the cost of the expression under the given metric, 0 for [`Solution::One`] and [`Solution::Zero`].
+    pub fn cost(&self, metric: CostMetric) -> usize {
+        cost::cost(self, metric)
+    }
+
+    /// Returns the number of variable occurrences in the expression.
+    pub fn literal_count(&self) -> usize {
+        self.expression()
+            .map_or(0, |(expression, _)| expression.iter().map(Vec::len).sum())
+    }
+
+    /// Formats the expression with the given [`Style`].
+    ///
+    /// [`Display`] is equivalent to formatting with [`Style::unicode`].
+    pub fn format(&self, style: &Style) -> String {
+        style.format_solution(self)
+    }
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(&Style::unicode()))
     }
 }
 
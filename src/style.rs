@@ -0,0 +1,234 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Form, Solution, Variable};
+
+/// Formatting options for [`Solution`]s, see [`Solution::format`].
+///
+/// There are presets for common notations, which can be adjusted through the public fields:
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let solution = qmc::minimize(
+///     &qmc::DEFAULT_VARIABLES[..3],
+///     &[0, 5],
+///     &[1, 3, 4, 6],
+///     qmc::SOP,
+///     false,
+///     None,
+/// )
+/// .unwrap()
+/// .pop()
+/// .unwrap();
+///
+/// assert_eq!(solution.format(&qmc::Style::ascii()), "(A & C) | (!A & !C)");
+/// assert_eq!(solution.format(&qmc::Style::textbook()), "AC + A'C'");
+///
+/// let style = qmc::Style {
+///     spaced: false,
+///     parentheses: qmc::Parentheses::Precedence,
+///     ..qmc::Style::c()
+/// };
+///
+/// assert_eq!(solution.format(&style), "A&&C||!A&&!C");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Style {
+    /// The conjunction operator. If empty, variables are juxtaposed.
+    pub and: String,
+    /// The disjunction operator. If empty, variables are juxtaposed.
+    pub or: String,
+    pub negation: Negation,
+    pub parentheses: Parentheses,
+    /// Whether to put spaces around the non-empty `and` and `or` operators.
+    pub spaced: bool,
+    /// The constant for [`Solution::One`].
+    pub one: String,
+    /// The constant for [`Solution::Zero`].
+    pub zero: String,
+}
+
+/// How a negated variable is written.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Negation {
+    /// The string is written before the variable, e.g. `~A`.
+    Prefix(String),
+    /// The string is written after the variable, e.g. `A'`.
+    Postfix(String),
+    /// The variable is overlined with the combining overline character (U+0305), e.g. `A̅`.
+    Overline,
+    /// The variable is enclosed between the two strings, e.g. `\overline{A}`.
+    Enclose(String, String),
+}
+
+/// When the terms of an expression are put in parentheses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Parentheses {
+    /// Every term with more than one variable, even if it is the only term.
+    Always,
+    /// Every term with more than one variable if there is more than one term.
+    MultipleTerms,
+    /// Only where the precedence of `and` over `or` requires it,
+    /// i.e. the terms of a [`Solution::POS`].
+    Precedence,
+}
+
+impl Style {
+    /// `(A ∧ C) ∨ (~A ∧ ~C)`, the notation of [`Display`](std::fmt::Display).
+    pub fn unicode() -> Self {
+        Style {
+            and: "∧".into(),
+            or: "∨".into(),
+            negation: Negation::Prefix("~".into()),
+            parentheses: Parentheses::MultipleTerms,
+            spaced: true,
+            one: "1".into(),
+            zero: "0".into(),
+        }
+    }
+
+    /// `(A & C) | (!A & !C)`
+    pub fn ascii() -> Self {
+        Style {
+            and: "&".into(),
+            or: "|".into(),
+            negation: Negation::Prefix("!".into()),
+            ..Self::unicode()
+        }
+    }
+
+    /// `(A && C) || (!A && !C)`
+    pub fn c() -> Self {
+        Style {
+            and: "&&".into(),
+            or: "||".into(),
+            ..Self::ascii()
+        }
+    }
+
+    /// `A and C or not A and not C`
+    pub fn python() -> Self {
+        Style {
+            and: "and".into(),
+            or: "or".into(),
+            negation: Negation::Prefix("not ".into()),
+            parentheses: Parentheses::Precedence,
+            spaced: true,
+            one: "True".into(),
+            zero: "False".into(),
+        }
+    }
+
+    /// `AC + A'C'`
+    pub fn textbook() -> Self {
+        Style {
+            and: "".into(),
+            or: "+".into(),
+            negation: Negation::Postfix("'".into()),
+            parentheses: Parentheses::Precedence,
+            spaced: true,
+            one: "1".into(),
+            zero: "0".into(),
+        }
+    }
+
+    /// `AC + A̅C̅`
+    pub fn overline() -> Self {
+        Style {
+            negation: Negation::Overline,
+            ..Self::textbook()
+        }
+    }
+
+    pub(crate) fn format_solution(&self, solution: &Solution) -> String {
+        let (expression, form) = match solution {
+            Solution::One => return self.one.clone(),
+            Solution::Zero => return self.zero.clone(),
+            Solution::SOP(expression) => (expression, Form::SOP),
+            Solution::POS(expression) => (expression, Form::POS),
+        };
+
+        let outer_operator = self.outer_operator(form);
+
+        expression
+            .iter()
+            .map(|variables| self.format_term(variables, form, expression.len()))
+            .collect::<Vec<_>>()
+            .join(&outer_operator)
+    }
+
+    pub(crate) fn format_term(
+        &self,
+        variables: &[Variable],
+        form: Form,
+        term_count: usize,
+    ) -> String {
+        let inner_operator = self.operator(if form == Form::SOP {
+            &self.and
+        } else {
+            &self.or
+        });
+
+        let term = variables
+            .iter()
+            .map(|variable| self.format_variable(variable))
+            .collect::<Vec<_>>()
+            .join(&inner_operator);
+
+        let needs_parentheses = variables.len() > 1
+            && match self.parentheses {
+                Parentheses::Always => true,
+                Parentheses::MultipleTerms => term_count > 1,
+                Parentheses::Precedence => term_count > 1 && form == Form::POS,
+            };
+
+        if needs_parentheses {
+            format!("({})", term)
+        } else {
+            term
+        }
+    }
+
+    pub(crate) fn outer_operator(&self, form: Form) -> String {
+        self.operator(if form == Form::SOP {
+            &self.or
+        } else {
+            &self.and
+        })
+    }
+
+    pub(crate) fn format_variable(&self, variable: &Variable) -> String {
+        if !variable.is_negated {
+            return variable.name.clone();
+        }
+
+        match &self.negation {
+            Negation::Prefix(prefix) => format!("{}{}", prefix, variable.name),
+            Negation::Postfix(postfix) => format!("{}{}", variable.name, postfix),
+            Negation::Overline => variable
+                .name
+                .chars()
+                .flat_map(|char| [char, '\u{0305}'])
+                .collect(),
+            Negation::Enclose(prefix, suffix) => format!("{}{}{}", prefix, variable.name, suffix),
+        }
+    }
+
+    fn operator(&self, operator: &str) -> String {
+        if self.spaced && !operator.is_empty() {
+            format!(" {} ", operator)
+        } else {
+            operator.to_owned()
+        }
+    }
+}
+
+impl Default for Style {
+    fn default() -> Self {
+        Self::unicode()
+    }
+}
@@ -1,6 +1,11 @@
+use std::cell::Cell;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
+use std::sync::Arc;
+use std::thread::{self, JoinHandle};
+use std::time::Instant;
 
-pub trait TTimeoutSignal: Default + Send + Sync {
+pub trait TTimeoutSignal {
     #[must_use]
     fn is_signaled(&self) -> bool;
 
@@ -9,15 +14,124 @@ pub trait TTimeoutSignal: Default + Send + Sync {
         !self.is_signaled()
     }
 
+    /// Like [`is_signaled`](TTimeoutSignal::is_signaled), but never skips reading a clock,
+    /// for loops doing so much work per check that skipping would overshoot the deadline noticeably.
+    #[must_use]
+    fn is_signaled_now(&self) -> bool {
+        self.is_signaled()
+    }
+
     fn signal(&self);
 }
 
 #[derive(Default)]
 pub struct TimeoutSignalNoOp;
 
-#[derive(Default)]
-pub struct TimeoutSignalAtomicBool {
-    signal: AtomicBool,
+/// Signaled once the deadline has passed, checked on the thread doing the work.
+///
+/// The clock is only read every [`DEADLINE_CHECK_INTERVAL`] checks, as reading it
+/// costs more than the work between most of them. Loops with expensive steps, like distributing
+/// the products in Petrick's method, use [`is_signaled_now`](TTimeoutSignal::is_signaled_now) instead.
+pub struct TimeoutSignalDeadline {
+    deadline: Instant,
+    signal: Cell<bool>,
+    check_count: Cell<u32>,
+}
+
+const DEADLINE_CHECK_INTERVAL: u32 = 16;
+
+/// Signaled by a thread of its own once the deadline has passed, so checking it only reads a flag.
+///
+/// Dropping it wakes the thread and joins it.
+pub struct TimeoutSignalTimer {
+    flag: CancellationToken,
+    sender: Option<Sender<()>>,
+    thread: Option<JoinHandle<()>>,
+}
+
+/// Signaled as soon as either of two signals is.
+pub struct TimeoutSignalEither<'a, A, B> {
+    first: &'a A,
+    second: &'a B,
+}
+
+/// A flag for stopping a minimization from another thread, see [`Minimizer::cancellation_token`](crate::Minimizer::cancellation_token).
+///
+/// Clones share the flag, so one can be passed to the minimizer and another one cancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl TimeoutSignalDeadline {
+    pub fn new(deadline: Instant) -> Self {
+        TimeoutSignalDeadline {
+            deadline,
+            signal: Cell::new(false),
+            check_count: Cell::new(0),
+        }
+    }
+}
+
+impl TimeoutSignalTimer {
+    pub fn spawn(deadline: Instant) -> Self {
+        let flag = CancellationToken::new();
+        let (sender, receiver) = mpsc::channel();
+
+        let thread_flag = flag.clone();
+        let thread = thread::Builder::new()
+            .name("quine-mccluskey timeout thread".into())
+            .spawn(move || {
+                let timeout = deadline.saturating_duration_since(Instant::now());
+
+                // The sender is only dropped, never used, so anything but a timeout means the work is done.
+                if let Err(RecvTimeoutError::Timeout) = receiver.recv_timeout(timeout) {
+                    thread_flag.cancel();
+                }
+            })
+            .expect("failed to spawn quine-mccluskey timeout thread");
+
+        TimeoutSignalTimer {
+            flag,
+            sender: Some(sender),
+            thread: Some(thread),
+        }
+    }
+}
+
+impl Drop for TimeoutSignalTimer {
+    fn drop(&mut self) {
+        drop(self.sender.take());
+
+        if let Some(thread) = self.thread.take() {
+            thread
+                .join()
+                .expect("failed to join quine-mccluskey timeout thread");
+        }
+    }
+}
+
+impl<'a, A, B> TimeoutSignalEither<'a, A, B> {
+    pub fn new(first: &'a A, second: &'a B) -> Self {
+        TimeoutSignalEither { first, second }
+    }
+}
+
+impl CancellationToken {
+    /// Creates a token that isn't cancelled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Makes the minimizations using this token return [`Error::Cancelled`](crate::Error::Cancelled).
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Release);
+    }
+
+    /// Returns whether [`cancel`](CancellationToken::cancel) was called on this token or a clone of it.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Acquire)
+    }
 }
 
 impl TTimeoutSignal for TimeoutSignalNoOp {
@@ -32,12 +146,69 @@ impl TTimeoutSignal for TimeoutSignalNoOp {
     fn signal(&self) {}
 }
 
-impl TTimeoutSignal for TimeoutSignalAtomicBool {
+impl TTimeoutSignal for TimeoutSignalDeadline {
+    fn is_signaled(&self) -> bool {
+        if self.signal.get() {
+            return true;
+        }
+
+        let check_count = self.check_count.get();
+        self.check_count.set(check_count.wrapping_add(1));
+
+        check_count % DEADLINE_CHECK_INTERVAL == 0 && self.is_signaled_now()
+    }
+
+    fn is_signaled_now(&self) -> bool {
+        if self.signal.get() {
+            return true;
+        }
+
+        // Once passed, the deadline stays passed without asking the clock again.
+        let is_signaled = Instant::now() >= self.deadline;
+
+        if is_signaled {
+            self.signal();
+        }
+
+        is_signaled
+    }
+
+    fn signal(&self) {
+        self.signal.set(true);
+    }
+}
+
+impl TTimeoutSignal for TimeoutSignalTimer {
+    fn is_signaled(&self) -> bool {
+        self.flag.is_cancelled()
+    }
+
+    fn signal(&self) {
+        self.flag.cancel();
+    }
+}
+
+impl<A: TTimeoutSignal, B: TTimeoutSignal> TTimeoutSignal for TimeoutSignalEither<'_, A, B> {
+    fn is_signaled(&self) -> bool {
+        self.first.is_signaled() || self.second.is_signaled()
+    }
+
+    fn is_signaled_now(&self) -> bool {
+        self.first.is_signaled_now() || self.second.is_signaled_now()
+    }
+
+    fn signal(&self) {
+        self.first.signal();
+        self.second.signal();
+    }
+}
+
+impl TTimeoutSignal for CancellationToken {
     fn is_signaled(&self) -> bool {
-        self.signal.load(Ordering::Acquire)
+        self.is_cancelled()
     }
 
     fn signal(&self) {
-        self.signal.store(true, Ordering::Release);
+        self.cancel();
     }
 }
@@ -0,0 +1,113 @@
+use std::collections::HashSet;
+use std::fmt::Display;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::bdd::{Bdd, Operation};
+use crate::{own_variables, validate_input, Error, Solution};
+
+/// The result of [`Solution::is_equivalent`] and [`Solution::verify_against`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Verification {
+    Equivalent,
+    /// An input assignment for which the solution has the wrong value.
+    Counterexample(Counterexample),
+}
+
+/// An input assignment for which a solution differs from what it was compared to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Counterexample {
+    /// The variables and their values.
+    pub assignment: Vec<(String, bool)>,
+    /// The value of the verified solution, the other side having the opposite value.
+    pub value: bool,
+}
+
+impl Verification {
+    pub fn is_equivalent(&self) -> bool {
+        matches!(self, Verification::Equivalent)
+    }
+}
+
+impl Display for Counterexample {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let assignment = self
+            .assignment
+            .iter()
+            .map(|(name, value)| format!("{} = {}", name, *value as u8))
+            .collect::<Vec<_>>();
+
+        write!(f, "{} gives {}", assignment.join(", "), self.value as u8)
+    }
+}
+
+pub(crate) fn is_equivalent(solution: &Solution, other: &Solution) -> Verification {
+    let mut variables: Vec<String> = vec![];
+
+    for expression in [solution, other].iter().filter_map(|s| s.expression()) {
+        for variable in expression.0.iter().flatten() {
+            if !variables.contains(&variable.name) {
+                variables.push(variable.name.clone());
+            }
+        }
+    }
+
+    // All variables of the solutions are known, so building the diagrams can't fail.
+    let bdd = Bdd::from_solution_unchecked(variables.clone(), solution).unwrap();
+    let other_bdd = Bdd::from_solution_unchecked(variables.clone(), other).unwrap();
+    let difference = bdd.apply(&other_bdd, Operation::Xor).unwrap();
+
+    match difference.satisfying_assignment() {
+        None => Verification::Equivalent,
+        Some(values) => {
+            let value = bdd.evaluate_assignment(&values);
+
+            Verification::Counterexample(Counterexample {
+                assignment: variables.into_iter().zip(values).collect(),
+                value,
+            })
+        }
+    }
+}
+
+pub(crate) fn verify_against<T: AsRef<str>>(
+    solution: &Solution,
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+) -> Result<Verification, Error> {
+    let variables = own_variables(variables);
+    let minterm_set = minterms.iter().copied().collect::<HashSet<_>>();
+    let maxterm_set = maxterms.iter().copied().collect::<HashSet<_>>();
+
+    validate_input(&variables, &minterm_set, &maxterm_set)?;
+
+    let bdd = Bdd::from_solution_unchecked(variables.clone(), solution)?;
+    let mut terms = minterm_set
+        .into_iter()
+        .map(|term| (term, true))
+        .chain(maxterm_set.into_iter().map(|term| (term, false)))
+        .collect::<Vec<_>>();
+    terms.sort_unstable();
+
+    for (term, expected) in terms {
+        if bdd.evaluate(term) != expected {
+            let variable_count = variables.len();
+            let assignment = variables
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| (name, term >> (variable_count - i - 1) & 1 == 1))
+                .collect();
+
+            return Ok(Verification::Counterexample(Counterexample {
+                assignment,
+                value: !expected,
+            }));
+        }
+    }
+
+    Ok(Verification::Equivalent)
+}
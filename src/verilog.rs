@@ -0,0 +1,292 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::identifier::{validate_ports, IdentifierRules, Namespace};
+use crate::{Error, Form, Negation, Parentheses, Solution, Style};
+
+#[rustfmt::skip]
+static RULES: IdentifierRules = IdentifierRules {
+    keywords: &[
+        "always", "and", "assign", "automatic", "begin", "buf", "bufif0", "bufif1", "case",
+        "casex", "casez", "cell", "cmos", "config", "deassign", "default", "defparam", "design",
+        "disable", "edge", "else", "end", "endcase", "endconfig", "endfunction", "endgenerate",
+        "endmodule", "endprimitive", "endspecify", "endtable", "endtask", "event", "for", "force",
+        "forever", "fork", "function", "generate", "genvar", "highz0", "highz1", "if", "ifnone",
+        "incdir", "include", "initial", "inout", "input", "instance", "integer", "join", "large",
+        "liblist", "library", "localparam", "macromodule", "medium", "module", "nand", "negedge",
+        "nmos", "nor", "noshowcancelled", "not", "notif0", "notif1", "or", "output", "parameter",
+        "pmos", "posedge", "primitive", "pull0", "pull1", "pulldown", "pullup",
+        "pulsestyle_ondetect", "pulsestyle_onevent", "rcmos", "real", "realtime", "reg", "release",
+        "repeat", "rnmos", "rpmos", "rtran", "rtranif0", "rtranif1", "scalared", "showcancelled",
+        "signed", "small", "specify", "specparam", "strong0", "strong1", "supply0", "supply1",
+        "table", "task", "time", "tran", "tranif0", "tranif1", "tri", "tri0", "tri1", "triand",
+        "trior", "trireg", "unsigned", "use", "uwire", "vectored", "wait", "wand", "weak0",
+        "weak1", "while", "wire", "wor", "xnor", "xor",
+    ],
+    is_case_insensitive: false,
+    has_strict_underscores: false,
+};
+
+/// Verilog generation options for [`Solution`]s.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["sel", "a", "b"];
+/// let solution = qmc::minimize(&variables, &[2, 3, 5, 7], &[0, 1, 4, 6], qmc::SOP, false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// assert_eq!(
+///     qmc::Verilog::default().module("mux", &variables, &[("y", &solution)]).unwrap(),
+///     "module mux (\n    input wire sel,\n    input wire a,\n    input wire b,\n    output wire y\n);\n\n    \
+///      assign y = (sel & b) | (~sel & a);\n\nendmodule\n"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Verilog {
+    /// Emit a gate-level netlist of `not`, `and` and `or` primitives instead of `assign` statements.
+    pub gate_level: bool,
+    /// Emit names that aren't legal Verilog identifiers as escaped identifiers (`\a+b `)
+    /// instead of sanitizing them (`a_b`). Characters other than printable ASCII are replaced with `_`.
+    pub escape_identifiers: bool,
+}
+
+impl Verilog {
+    /// Generates a module with an input port for each of `inputs`
+    /// and an output port driven by the [`Solution`] for each of `outputs`.
+    ///
+    /// Returns [`Error::DuplicateVariables`] if a port name is used more than once
+    /// and [`Error::UnknownVariable`] if a solution contains a variable that is not in `inputs`.
+    pub fn module<T: AsRef<str>, U: AsRef<str>>(
+        &self,
+        name: &str,
+        inputs: &[T],
+        outputs: &[(U, &Solution)],
+    ) -> Result<String, Error> {
+        let inputs = inputs.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let output_names = outputs
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>();
+
+        validate_ports(
+            &inputs,
+            &output_names,
+            outputs.iter().map(|(_, solution)| *solution),
+        )?;
+
+        let mut namespace = Namespace::new(&RULES);
+        let input_identifiers = inputs
+            .iter()
+            .map(|&input| (input, self.identifier(input, &mut namespace)))
+            .collect::<HashMap<_, _>>();
+        let output_identifiers = output_names
+            .iter()
+            .map(|&output| self.identifier(output, &mut namespace))
+            .collect::<Vec<_>>();
+
+        let mut verilog = format!(
+            "module {} (\n",
+            self.identifier(name, &mut Namespace::new(&RULES))
+        );
+
+        let ports = inputs
+            .iter()
+            .map(|input| format!("    input wire {}", input_identifiers[input]))
+            .chain(
+                output_identifiers
+                    .iter()
+                    .map(|output| format!("    output wire {}", output)),
+            )
+            .collect::<Vec<_>>();
+
+        verilog.push_str(&ports.join(",\n"));
+        verilog.push_str("\n);\n\n");
+
+        let solutions = outputs
+            .iter()
+            .map(|(_, solution)| {
+                solution.rename_variables(|variable| input_identifiers[variable].clone())
+            })
+            .collect::<Vec<_>>();
+
+        if self.gate_level {
+            write_netlist(
+                &mut verilog,
+                &inputs,
+                &input_identifiers,
+                &output_identifiers,
+                &solutions,
+                &mut namespace,
+            );
+        } else {
+            for (output, solution) in output_identifiers.iter().zip(&solutions) {
+                writeln!(
+                    verilog,
+                    "    assign {} = {};",
+                    output,
+                    solution.format(&style())
+                )
+                .unwrap();
+            }
+        }
+
+        verilog.push_str("\nendmodule\n");
+
+        Ok(verilog)
+    }
+
+    fn identifier(&self, name: &str, namespace: &mut Namespace) -> String {
+        if self.escape_identifiers && !RULES.is_valid(name) {
+            // `\a_b ` and `a_b` are the same identifier, so the name is reserved without the escape.
+            let name = name
+                .chars()
+                .map(|char| if matches!(char, '!'..='~') { char } else { '_' })
+                .collect::<String>();
+            let mut identifier = name.clone();
+            let mut suffix = 1;
+
+            while !namespace.reserve(&identifier) {
+                identifier = format!("{}_{}", name, suffix);
+                suffix += 1;
+            }
+
+            format!("\\{} ", identifier)
+        } else {
+            namespace.insert(name)
+        }
+    }
+}
+
+fn write_netlist(
+    verilog: &mut String,
+    inputs: &[&str],
+    input_identifiers: &HashMap<&str, String>,
+    output_identifiers: &[String],
+    solutions: &[Solution],
+    namespace: &mut Namespace,
+) {
+    let negated_identifiers = solutions
+        .iter()
+        .filter_map(Solution::expression)
+        .flat_map(|(expression, _)| expression.iter().flatten())
+        .filter(|variable| variable.is_negated)
+        .map(|variable| variable.name.clone())
+        .collect::<HashSet<_>>();
+
+    let mut wires = vec![];
+    let mut gates = vec![];
+    let mut inverters = HashMap::new();
+
+    for input in inputs {
+        let identifier = &input_identifiers[input];
+
+        if negated_identifiers.contains(identifier) {
+            let inverter = namespace.insert(&format!("{}_n", identifier.trim_start_matches('\\')));
+            gates.push(format!("not ({}, {});", inverter, identifier));
+            wires.push(inverter.clone());
+            inverters.insert(identifier.clone(), inverter);
+        }
+    }
+
+    let mut assigns = vec![];
+
+    for (output, solution) in output_identifiers.iter().zip(solutions) {
+        let (expression, form) = match solution.expression() {
+            Some(expression) => expression,
+            None => {
+                assigns.push(format!(
+                    "assign {} = {};",
+                    output,
+                    solution.format(&style())
+                ));
+                continue;
+            }
+        };
+
+        let (inner_gate, outer_gate) = if form == Form::SOP {
+            ("and", "or")
+        } else {
+            ("or", "and")
+        };
+
+        let mut term_signals = vec![];
+
+        for (i, variables) in expression.iter().enumerate() {
+            let signals = variables
+                .iter()
+                .map(|variable| {
+                    if variable.is_negated {
+                        inverters[&variable.name].clone()
+                    } else {
+                        variable.name.clone()
+                    }
+                })
+                .collect::<Vec<_>>();
+
+            if signals.len() == 1 {
+                term_signals.push(signals[0].clone());
+            } else if expression.len() == 1 {
+                gates.push(format!(
+                    "{} ({}, {});",
+                    inner_gate,
+                    output,
+                    signals.join(", ")
+                ));
+            } else {
+                let wire = namespace.insert(&format!("{}_{}", output.trim_start_matches('\\'), i));
+                gates.push(format!(
+                    "{} ({}, {});",
+                    inner_gate,
+                    wire,
+                    signals.join(", ")
+                ));
+                wires.push(wire.clone());
+                term_signals.push(wire);
+            }
+        }
+
+        if expression.len() == 1 && term_signals.len() == 1 {
+            gates.push(format!("buf ({}, {});", output, term_signals[0]));
+        } else if expression.len() > 1 {
+            gates.push(format!(
+                "{} ({}, {});",
+                outer_gate,
+                output,
+                term_signals.join(", ")
+            ));
+        }
+    }
+
+    for wire in &wires {
+        writeln!(verilog, "    wire {};", wire).unwrap();
+    }
+
+    if !wires.is_empty() {
+        verilog.push('\n');
+    }
+
+    for line in gates.iter().chain(&assigns) {
+        writeln!(verilog, "    {}", line).unwrap();
+    }
+}
+
+fn style() -> Style {
+    Style {
+        and: "&".into(),
+        or: "|".into(),
+        negation: Negation::Prefix("~".into()),
+        parentheses: Parentheses::MultipleTerms,
+        spaced: true,
+        one: "1'b1".into(),
+        zero: "1'b0".into(),
+    }
+}
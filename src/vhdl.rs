@@ -0,0 +1,327 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write;
+use std::iter;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::identifier::{validate_ports, IdentifierRules, Namespace};
+use crate::{own_variables, validate_input, Error, Negation, Parentheses, Solution, Style};
+
+#[rustfmt::skip]
+static RULES: IdentifierRules = IdentifierRules {
+    keywords: &[
+        "abs", "access", "after", "alias", "all", "and", "architecture", "array", "assert",
+        "assume", "attribute", "begin", "block", "body", "buffer", "bus", "case", "component",
+        "configuration", "constant", "context", "cover", "default", "disconnect", "downto", "else",
+        "elsif", "end", "entity", "exit", "fairness", "file", "for", "force", "function",
+        "generate", "generic", "group", "guarded", "if", "impure", "in", "inertial", "inout", "is",
+        "label", "library", "linkage", "literal", "loop", "map", "mod", "nand", "new", "next",
+        "nor", "not", "null", "of", "on", "open", "or", "others", "out", "package", "parameter",
+        "port", "postponed", "procedure", "process", "property", "protected", "pure", "range",
+        "record", "register", "reject", "release", "rem", "report", "restrict", "return", "rol",
+        "ror", "select", "sequence", "severity", "shared", "signal", "sla", "sll", "sra", "srl",
+        "strong", "subtype", "then", "to", "transport", "type", "unaffected", "units", "until",
+        "use", "variable", "vmode", "vprop", "vunit", "wait", "when", "while", "with", "xnor",
+        "xor",
+    ],
+    is_case_insensitive: true,
+    has_strict_underscores: true,
+};
+
+/// VHDL generation options for [`Solution`]s.
+///
+/// Names that aren't legal VHDL identifiers are sanitized, e.g. `a+b` becomes `a_b`.
+///
+/// # Example
+///
+/// ```rust
+/// use quine_mccluskey as qmc;
+///
+/// let variables = ["sel", "a", "b"];
+/// let solution = qmc::minimize(&variables, &[2, 3, 5, 7], &[0, 1, 4, 6], qmc::SOP, false, None)
+///     .unwrap()
+///     .pop()
+///     .unwrap();
+///
+/// let vhdl = qmc::Vhdl::default();
+/// let design = vhdl.entity("mux", &variables, &[("y", &solution)]).unwrap();
+/// let testbench = vhdl
+///     .testbench("mux", &variables, &[("y", &[2, 3, 5, 7], &[0, 1, 4, 6])])
+///     .unwrap();
+///
+/// assert!(design.contains("    y <= (sel and b) or (not sel and a);\n"));
+/// assert!(testbench.contains("    constant y_expected : std_logic_vector(0 to 7) := \"00110101\";\n"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Vhdl {
+    /// The name of the generated architecture, `rtl` by default.
+    pub architecture: String,
+}
+
+impl Vhdl {
+    /// Generates an entity with an input port for each of `inputs` and an output port for each of `outputs`,
+    /// and an architecture driving each output with a concurrent signal assignment of its [`Solution`].
+    ///
+    /// Returns [`Error::DuplicateVariables`] if a port name is used more than once
+    /// and [`Error::UnknownVariable`] if a solution contains a variable that is not in `inputs`.
+    pub fn entity<T: AsRef<str>, U: AsRef<str>>(
+        &self,
+        name: &str,
+        inputs: &[T],
+        outputs: &[(U, &Solution)],
+    ) -> Result<String, Error> {
+        let inputs = inputs.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let output_names = outputs
+            .iter()
+            .map(|(name, _)| name.as_ref())
+            .collect::<Vec<_>>();
+
+        validate_ports(
+            &inputs,
+            &output_names,
+            outputs.iter().map(|(_, solution)| *solution),
+        )?;
+
+        let ports = Ports::new(name, &inputs, &output_names);
+        let architecture = RULES.sanitize(&self.architecture);
+
+        let mut vhdl = String::from("library ieee;\nuse ieee.std_logic_1164.all;\n\n");
+
+        writeln!(vhdl, "entity {} is\n    port (", ports.entity).unwrap();
+
+        let declarations = ports
+            .inputs
+            .iter()
+            .map(|input| format!("        {} : in std_logic", input))
+            .chain(
+                ports
+                    .outputs
+                    .iter()
+                    .map(|output| format!("        {} : out std_logic", output)),
+            )
+            .collect::<Vec<_>>();
+
+        vhdl.push_str(&declarations.join(";\n"));
+        writeln!(vhdl, "\n    );\nend entity {};\n", ports.entity).unwrap();
+        writeln!(
+            vhdl,
+            "architecture {} of {} is\nbegin",
+            architecture, ports.entity
+        )
+        .unwrap();
+
+        let identifiers = inputs
+            .iter()
+            .copied()
+            .zip(ports.inputs.iter().cloned())
+            .collect::<HashMap<_, _>>();
+
+        for (output, (_, solution)) in ports.outputs.iter().zip(outputs) {
+            let solution = solution.rename_variables(|variable| identifiers[variable].clone());
+            writeln!(vhdl, "    {} <= {};", output, solution.format(&style())).unwrap();
+        }
+
+        writeln!(vhdl, "end architecture {};", architecture).unwrap();
+
+        Ok(vhdl)
+    }
+
+    /// Generates a testbench for the entity generated by [`Vhdl::entity`] with the same `name`, `inputs` and outputs.
+    ///
+    /// The testbench applies every input combination and asserts that each output is `'1'` for its `minterms`
+    /// and `'0'` for its `maxterms`. The rest of the terms are don't care conditions and aren't checked.
+    ///
+    /// Returns an [`Error`] for the same bad input as [`minimize`](crate::minimize).
+    #[allow(clippy::type_complexity)]
+    pub fn testbench<T: AsRef<str>, U: AsRef<str>>(
+        &self,
+        name: &str,
+        inputs: &[T],
+        outputs: &[(U, &[u32], &[u32])],
+    ) -> Result<String, Error> {
+        let variables = own_variables(inputs);
+
+        for (_, minterms, maxterms) in outputs {
+            let minterms = minterms.iter().copied().collect::<HashSet<_>>();
+            let maxterms = maxterms.iter().copied().collect::<HashSet<_>>();
+
+            validate_input(&variables, &minterms, &maxterms)?;
+        }
+
+        let inputs = inputs.iter().map(AsRef::as_ref).collect::<Vec<_>>();
+        let output_names = outputs
+            .iter()
+            .map(|(name, _, _)| name.as_ref())
+            .collect::<Vec<_>>();
+
+        validate_ports(&inputs, &output_names, iter::empty())?;
+
+        let mut ports = Ports::new(name, &inputs, &output_names);
+        let testbench = RULES.sanitize(&format!("{}_tb", ports.entity));
+        let index = ports.namespace.insert("i");
+        let term = ports.namespace.insert("term");
+        let uut = ports.namespace.insert("uut");
+        let stimulus = ports.namespace.insert("stimulus");
+        let variable_count = inputs.len();
+        let term_count = 1 << variable_count;
+
+        let mut vhdl = String::from(
+            "library ieee;\nuse ieee.std_logic_1164.all;\nuse ieee.numeric_std.all;\n\n",
+        );
+
+        writeln!(vhdl, "entity {} is\nend entity {};\n", testbench, testbench).unwrap();
+        writeln!(vhdl, "architecture test of {} is", testbench).unwrap();
+
+        for input in &ports.inputs {
+            writeln!(vhdl, "    signal {} : std_logic;", input).unwrap();
+        }
+
+        for output in &ports.outputs {
+            writeln!(vhdl, "    signal {} : std_logic;", output).unwrap();
+        }
+
+        let mut expected_constants = Vec::with_capacity(outputs.len());
+
+        for (output, (_, minterms, maxterms)) in ports.outputs.iter().zip(outputs) {
+            let mut expected = vec!['-'; term_count];
+
+            for &term in minterms.iter() {
+                expected[term as usize] = '1';
+            }
+
+            for &term in maxterms.iter() {
+                expected[term as usize] = '0';
+            }
+
+            let constant = ports.namespace.insert(&format!("{}_expected", output));
+            writeln!(
+                vhdl,
+                "    constant {} : std_logic_vector(0 to {}) := \"{}\";",
+                constant,
+                term_count - 1,
+                expected.into_iter().collect::<String>()
+            )
+            .unwrap();
+            expected_constants.push(constant);
+        }
+
+        writeln!(
+            vhdl,
+            "begin\n    {} : entity work.{}\n        port map (",
+            uut, ports.entity
+        )
+        .unwrap();
+
+        let port_map = ports
+            .inputs
+            .iter()
+            .chain(&ports.outputs)
+            .map(|port| format!("            {} => {}", port, port))
+            .collect::<Vec<_>>();
+
+        vhdl.push_str(&port_map.join(",\n"));
+        vhdl.push_str("\n        );\n\n");
+
+        writeln!(
+            vhdl,
+            "    {} : process\n        variable {} : std_logic_vector({} downto 0);\n    begin",
+            stimulus,
+            term,
+            variable_count - 1
+        )
+        .unwrap();
+        writeln!(
+            vhdl,
+            "        for {} in 0 to {} loop",
+            index,
+            term_count - 1
+        )
+        .unwrap();
+        writeln!(
+            vhdl,
+            "            {} := std_logic_vector(to_unsigned({}, {}));",
+            term, index, variable_count
+        )
+        .unwrap();
+
+        for (i, input) in ports.inputs.iter().enumerate() {
+            writeln!(
+                vhdl,
+                "            {} <= {}({});",
+                input,
+                term,
+                variable_count - i - 1
+            )
+            .unwrap();
+        }
+
+        vhdl.push_str("            wait for 1 ns;\n");
+
+        for (output, constant) in ports.outputs.iter().zip(&expected_constants) {
+            writeln!(
+                vhdl,
+                "            assert {constant}({index}) = '-' or {output} = {constant}({index})\n                \
+                 report \"{output} is wrong for term \" & integer'image({index}) severity error;",
+                constant = constant,
+                output = output,
+                index = index
+            )
+            .unwrap();
+        }
+
+        writeln!(
+            vhdl,
+            "        end loop;\n        wait;\n    end process {};",
+            stimulus
+        )
+        .unwrap();
+        vhdl.push_str("end architecture test;\n");
+
+        Ok(vhdl)
+    }
+}
+
+impl Default for Vhdl {
+    fn default() -> Self {
+        Vhdl {
+            architecture: "rtl".into(),
+        }
+    }
+}
+
+struct Ports {
+    entity: String,
+    inputs: Vec<String>,
+    outputs: Vec<String>,
+    namespace: Namespace<'static>,
+}
+
+impl Ports {
+    fn new(entity: &str, inputs: &[&str], outputs: &[&str]) -> Self {
+        let mut namespace = Namespace::new(&RULES);
+
+        Ports {
+            entity: RULES.sanitize(entity),
+            inputs: inputs.iter().map(|input| namespace.insert(input)).collect(),
+            outputs: outputs
+                .iter()
+                .map(|output| namespace.insert(output))
+                .collect(),
+            namespace,
+        }
+    }
+}
+
+fn style() -> Style {
+    Style {
+        and: "and".into(),
+        or: "or".into(),
+        negation: Negation::Prefix("not ".into()),
+        parentheses: Parentheses::MultipleTerms,
+        spaced: true,
+        one: "'1'".into(),
+        zero: "'0'".into(),
+    }
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn optimal() {
+    let variables = ["A", "B", "C"];
+    let (minterms, maxterms) = ([0, 1, 2, 5, 6, 7], [3, 4]);
+
+    let anytime = qmc::Minimizer::new(&variables)
+        .minterms(&minterms)
+        .maxterms(&maxterms)
+        .all_solutions(true)
+        .solve_anytime()
+        .unwrap();
+
+    assert!(anytime.is_optimal);
+    assert_eq!(anytime.optimality_gap, Some(0));
+    assert_eq!(
+        to_strings(&anytime.solutions),
+        to_strings(&qmc::minimize(&variables, &minterms, &maxterms, qmc::SOP, true, None).unwrap())
+    );
+}
+
+#[test]
+fn timeout_while_solving_chart() {
+    let variables = &qmc::DEFAULT_VARIABLES[..10];
+    let (minterms, maxterms) = hard_function();
+
+    for form in [qmc::SOP, qmc::POS] {
+        // Finding the prime implicants takes a few milliseconds, solving the chart far longer.
+        let anytime = qmc::Minimizer::new(variables)
+            .minterms(&minterms)
+            .maxterms(&maxterms)
+            .form(form)
+            .timeout(Duration::from_millis(500))
+            .solve_anytime()
+            .unwrap();
+
+        assert!(!anytime.is_optimal);
+        assert!(anytime.optimality_gap.is_some());
+        assert_eq!(anytime.solutions.len(), 1);
+        assert!(anytime.solutions[0]
+            .verify_against(variables, &minterms, &maxterms)
+            .unwrap()
+            .is_equivalent());
+    }
+}
+
+#[test]
+fn timeout_while_finding_prime_implicants() {
+    let variables = &qmc::DEFAULT_VARIABLES[..10];
+    let (minterms, maxterms) = hard_function();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let anytime = qmc::Minimizer::new(variables)
+            .minterms(&minterms)
+            .maxterms(&maxterms)
+            .form(form)
+            .timeout(Duration::ZERO)
+            .solve_anytime()
+            .unwrap();
+
+        assert!(!anytime.is_optimal);
+        assert_eq!(anytime.optimality_gap, None);
+        assert!(anytime.solutions[0]
+            .verify_against(variables, &minterms, &maxterms)
+            .unwrap()
+            .is_equivalent());
+    }
+}
+
+#[test]
+fn cancelled() {
+    let token = qmc::CancellationToken::new();
+
+    token.cancel();
+
+    assert!(matches!(
+        qmc::Minimizer::new(&["A", "B"])
+            .minterms(&[1])
+            .cancellation_token(&token)
+            .timeout(Duration::from_secs(1))
+            .solve_anytime(),
+        Err(qmc::Error::Cancelled)
+    ));
+}
+
+/// A function of 10 variables taking far longer than a second to minimize.
+fn hard_function() -> (Vec<u32>, Vec<u32>) {
+    let kinds = (0..1 << 10)
+        .map(|term: u32| (term.wrapping_mul(2654435761) >> 13) % 3)
+        .collect::<Vec<_>>();
+    let terms = |kind| {
+        (0..1 << 10)
+            .filter(|&term| kinds[term as usize] == kind)
+            .collect::<Vec<_>>()
+    };
+
+    (terms(0), terms(1))
+}
+
+fn to_strings(solutions: &[qmc::Solution]) -> Vec<String> {
+    solutions.iter().map(ToString::to_string).collect()
+}
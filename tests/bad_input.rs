@@ -171,6 +171,16 @@ fn term_out_of_bounds3() {
     qmc::minimize_maxterms(&["A"], &[2], &[], false, None).unwrap();
 }
 
+#[test]
+fn term_out_of_bounds_message() {
+    let error = qmc::minimize(&["A"], &[2], &[], qmc::SOP, false, None).unwrap_err();
+
+    assert_eq!(
+        error.to_string(),
+        "Terms out of bounds: {2} (expected < 2 for 1 variables)"
+    );
+}
+
 #[test]
 #[should_panic(expected = "TermConflict")]
 fn conflicting_terms() {
@@ -0,0 +1,168 @@
+use quine_mccluskey as qmc;
+use quine_mccluskey::{Bdd, Operation};
+
+#[test]
+fn from_terms() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+
+    let zero = Bdd::from_terms(variables, &[], &[1, 2]).unwrap();
+    let one = Bdd::from_terms(variables, &[0, 1, 2], &[3, 4, 5, 6, 7]).unwrap();
+    let parity = Bdd::from_terms(variables, &[1, 2, 4, 7], &[]).unwrap();
+
+    assert_eq!((zero.node_count(), one.node_count()), (0, 0));
+    assert!(!zero.evaluate(1) && one.evaluate(7));
+    assert_eq!(parity.node_count(), 5);
+
+    for term in 0..8 {
+        assert_eq!(parity.evaluate(term), term.count_ones() % 2 == 1);
+    }
+}
+
+#[test]
+fn dont_cares() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    // B ∧ C where A = 1 is unspecified, so A isn't tested.
+    let bdd = Bdd::from_terms(variables, &[3], &[4, 5, 6, 7]).unwrap();
+
+    assert_eq!(bdd.node_count(), 2);
+    assert_eq!(bdd, Bdd::from_terms(variables, &[3, 7], &[]).unwrap());
+}
+
+#[test]
+fn from_solution() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let minterms = [0, 2, 5, 6, 7, 8, 10, 13, 15];
+    let maxterms = (0..16)
+        .filter(|term| !minterms.contains(term))
+        .collect::<Vec<_>>();
+    let expected = Bdd::from_terms(variables, &minterms, &[]).unwrap();
+
+    for form in [qmc::SOP, qmc::POS] {
+        let solution = qmc::minimize(variables, &minterms, &maxterms, form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        assert_eq!(Bdd::from_solution(variables, &solution).unwrap(), expected);
+    }
+
+    assert_eq!(
+        Bdd::from_solution(variables, &qmc::Solution::One).unwrap(),
+        Bdd::from_terms(variables, &(0..16).collect::<Vec<_>>(), &[]).unwrap()
+    );
+    assert!(matches!(
+        Bdd::from_solution(&variables[..2], &qmc::minimize_minterms(variables, &[1], &[], false, None).unwrap()[0]),
+        Err(qmc::Error::UnknownVariable(name)) if name == "C"
+    ));
+}
+
+#[test]
+fn reorder() {
+    let variables = ["a1", "a2", "a3", "b1", "b2", "b3"];
+    // (a1 ∧ b1) ∨ (a2 ∧ b2) ∨ (a3 ∧ b3)
+    let minterms = (0..64)
+        .filter(|term| (term >> 3) & term != 0)
+        .collect::<Vec<_>>();
+    let bdd = Bdd::from_terms(&variables, &minterms, &[]).unwrap();
+    let interleaved = bdd.reorder(&["a1", "b1", "a2", "b2"]).unwrap();
+
+    assert_eq!(bdd.node_count(), 14);
+    assert_eq!(interleaved.node_count(), 6);
+    assert_eq!(interleaved.order(), ["a1", "b1", "a2", "b2", "a3", "b3"]);
+    assert_eq!(interleaved.variables(), variables);
+    assert_eq!(interleaved.reorder(&variables).unwrap(), bdd);
+
+    for term in 0..64 {
+        assert_eq!(interleaved.evaluate(term), minterms.contains(&term));
+    }
+
+    assert!(matches!(
+        bdd.reorder(&["a1", "a1"]),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+    assert!(matches!(
+        bdd.reorder(&["c"]),
+        Err(qmc::Error::UnknownVariable(_))
+    ));
+}
+
+#[test]
+fn apply() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let f_terms = [1, 3, 4, 9, 10, 14, 15];
+    let g_terms = [0, 3, 4, 5, 11, 14];
+    let f = Bdd::from_terms(variables, &f_terms, &[]).unwrap();
+    let g = Bdd::from_terms(variables, &g_terms, &[])
+        .unwrap()
+        .reorder(&["D", "C", "B", "A"])
+        .unwrap();
+
+    let operations = [
+        (Operation::And, (|a, b| a && b) as fn(bool, bool) -> bool),
+        (Operation::Or, |a, b| a || b),
+        (Operation::Xor, |a, b| a != b),
+    ];
+
+    for (operation, function) in operations {
+        let result = f.apply(&g, operation).unwrap();
+        let expected = (0..16)
+            .filter(|term| function(f_terms.contains(term), g_terms.contains(term)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(result.order(), variables);
+        assert_eq!(result, Bdd::from_terms(variables, &expected, &[]).unwrap());
+    }
+
+    assert_eq!(
+        f.not(),
+        Bdd::from_terms(
+            variables,
+            &(0..16)
+                .filter(|term| !f_terms.contains(term))
+                .collect::<Vec<_>>(),
+            &[]
+        )
+        .unwrap()
+    );
+    assert_eq!(f.apply(&f.not(), Operation::Or).unwrap().node_count(), 0);
+
+    // A function of fewer variables is combined as one not depending on the others.
+    let a = Bdd::from_terms(&["A"], &[1], &[]).unwrap();
+
+    assert_eq!(
+        f.apply(&a, Operation::And).unwrap(),
+        Bdd::from_terms(variables, &[9, 10, 14, 15], &[]).unwrap()
+    );
+    assert!(matches!(
+        a.apply(&f, Operation::And),
+        Err(qmc::Error::UnknownVariable(_))
+    ));
+}
+
+#[test]
+fn dot() {
+    let bdd = Bdd::from_terms(&["a", "\"b\""], &[1, 2], &[]).unwrap();
+
+    assert_eq!(
+        bdd.to_dot("xor"),
+        r#"digraph "xor" {
+    n4 [label="a"];
+    n3 [label="\"b\""];
+    n2 [label="\"b\""];
+    zero [label="0", shape=box];
+    one [label="1", shape=box];
+
+    n4 -> n2 [style=dashed];
+    n4 -> n3;
+    n3 -> one [style=dashed];
+    n3 -> zero;
+    n2 -> zero [style=dashed];
+    n2 -> one;
+}
+"#
+    );
+    assert_eq!(
+        Bdd::from_terms(&["a"], &[], &[]).unwrap().to_dot("zero"),
+        "digraph \"zero\" {\n    zero [label=\"0\", shape=box];\n}\n"
+    );
+}
@@ -0,0 +1,216 @@
+#![cfg(feature = "build")]
+
+use std::fs;
+use std::path::PathBuf;
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn truth_table() {
+    let path = spec_file(
+        "mux.txt",
+        "# A 2-to-1 multiplexer.
+sel a b | y
+  0 0 - | 0
+  0 1 - | 1
+  1 - 0 | 0
+  1 - 1 | 1
+",
+    );
+
+    assert_eq!(
+        qmc::Build::new().file(&path).generate().unwrap(),
+        format!(
+            "// Generated by quine-mccluskey. Do not edit.
+
+// Generated from `{}`.
+pub mod mux {{
+    pub fn y(sel: bool, a: bool, b: bool) -> bool {{
+        (sel && b) || (!sel && a)
+    }}
+}}
+",
+            path.display()
+        )
+    );
+}
+
+#[test]
+fn comments() {
+    let path = spec_file(
+        "comments.txt",
+        "a b | y # the header
+0 - | 0 # a = 0
+    # b doesn't matter when a = 1
+1 - | 1
+",
+    );
+
+    assert!(qmc::Build::new()
+        .file(&path)
+        .generate()
+        .unwrap()
+        .contains("pub fn y(a: bool, _b: bool) -> bool {\n        a\n    }"));
+}
+
+#[test]
+fn pla() {
+    let path = spec_file(
+        "decoder.pla",
+        ".i 2
+.o 3
+.ilb a b
+.ob y0 y1 any
+.p 3
+00 1-0
+01 010 # comment
+1- 001
+.e
+",
+    );
+
+    let rust = qmc::Build::new().file(&path).generate().unwrap();
+
+    assert!(rust.contains(
+        "pub mod decoder {
+    pub fn y0(a: bool, b: bool) -> bool {
+        !a && !b
+    }
+
+    pub fn y1(a: bool, _b: bool) -> bool {
+        !a
+    }
+
+    pub fn any(a: bool, _b: bool) -> bool {
+        a
+    }
+}"
+    ));
+
+    let path = spec_file(
+        "default_names.pla",
+        ".i 2
+.o 1
+.type fr
+00 1
+11 0
+",
+    );
+
+    assert!(qmc::Build::new()
+        .file(&path)
+        .parameters(qmc::Parameters::Bits)
+        .generate()
+        .unwrap()
+        .contains("pub fn f0(x: u32) -> bool {\n        (x >> 1) & 1 == 0\n    }"));
+}
+
+#[test]
+fn json() {
+    let path = spec_file(
+        "spec.json",
+        r#"{
+    "name": "logic",
+    "inputs": ["a", "b", "c"],
+    "outputs": [
+        { "name": "sop", "minterms": [0, 5], "dont_cares": [2, 7] },
+        { "name": "pos", "maxterms": [1, 3, 4, 6], "dont_cares": [2, 7] },
+        { "name": "type", "minterms": [0, 5], "maxterms": [1, 3, 4, 6] }
+    ]
+}"#,
+    );
+
+    let rust = qmc::Build::new().file(&path).generate().unwrap();
+
+    assert!(rust.contains("pub mod logic {"));
+    assert!(rust.contains(
+        "    pub fn sop(a: bool, _b: bool, c: bool) -> bool {\n        (a && c) || (!a && !c)\n    }"
+    ));
+    assert!(rust.contains(
+        "    pub fn pos(a: bool, _b: bool, c: bool) -> bool {\n        (a || !c) && (!a || c)\n    }"
+    ));
+    assert!(rust.contains("    pub fn type_(a: bool, _b: bool, c: bool) -> bool {"));
+}
+
+#[test]
+fn module_names() {
+    let first = spec_file("names/1.txt", "a | y\n1 | 1\n0 | 0\n");
+    let second = spec_file("names/1.pla", ".i 1\n.o 1\n1 1\n");
+
+    let rust = qmc::Build::new()
+        .files([&first, &second])
+        .generate()
+        .unwrap();
+
+    assert!(rust.contains("pub mod _1 {"));
+    assert!(rust.contains("pub mod _1_1 {"));
+}
+
+#[test]
+fn diagnostics() {
+    let error = |name: &str, text: &str| {
+        let path = spec_file(name, text);
+        let error = qmc::Build::new().file(&path).generate().unwrap_err();
+
+        (path, error.to_string())
+    };
+
+    let (path, message) = error("conflict.txt", "a b | y\n0 - | 1\n00 | 0\n");
+    assert_eq!(
+        message,
+        format!(
+            "{}:3: term 0 of output `y` is 0 here but 1 on line 2",
+            path.display()
+        )
+    );
+
+    let (path, message) = error("columns.txt", "a b | y\n0 | 1\n");
+    assert_eq!(message, format!("{}:2: expected 2 inputs", path.display()));
+
+    let (path, message) = error("keyword.pla", ".i 1\n.o 1\n.mv 2\n");
+    assert_eq!(
+        message,
+        format!("{}:3: unsupported keyword `.mv`", path.display())
+    );
+
+    let (path, message) = error(
+        "conflict.json",
+        r#"{ "inputs": ["a"], "outputs": [{ "name": "y", "minterms": [1], "dont_cares": [1] }] }"#,
+    );
+    assert_eq!(
+        message,
+        format!(
+            "{}: output `y`: Conflicting terms between term sets: {{1}}",
+            path.display()
+        )
+    );
+
+    let (path, message) = error(
+        "invalid.json",
+        r#"{ "inputs": ["a"], "outputs": [{ "name": "y", "dont_cares": [1] }] }"#,
+    );
+    assert_eq!(
+        message,
+        format!(
+            "{}: output `y`: expected `minterms` or `maxterms`",
+            path.display()
+        )
+    );
+
+    let error = qmc::Build::new()
+        .file(spec_file("missing", "").with_extension("txt"))
+        .generate()
+        .unwrap_err();
+    assert!(matches!(error, qmc::BuildError::Io { .. }));
+}
+
+fn spec_file(name: &str, text: &str) -> PathBuf {
+    let path = PathBuf::from(env!("CARGO_TARGET_TMPDIR"))
+        .join("build")
+        .join(name);
+
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(&path, text).unwrap();
+
+    path
+}
@@ -0,0 +1,73 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use quine_mccluskey as qmc;
+
+#[test]
+fn cancel_from_another_thread() {
+    for timeout in [None, Some(Duration::from_secs(60))] {
+        let token = qmc::CancellationToken::new();
+        let mut minimizer = hard_function().cancellation_token(&token);
+
+        if let Some(timeout) = timeout {
+            minimizer = minimizer.timeout(timeout);
+        }
+
+        let canceller = {
+            let token = token.clone();
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(200));
+                token.cancel();
+            })
+        };
+
+        let start = Instant::now();
+
+        assert!(matches!(minimizer.solve(), Err(qmc::Error::Cancelled)));
+        assert!(start.elapsed() < Duration::from_secs(30));
+
+        canceller.join().unwrap();
+    }
+}
+
+#[test]
+fn timeout_before_cancel() {
+    let token = qmc::CancellationToken::new();
+    let mut minimizer = hard_function()
+        .cancellation_token(&token)
+        .timeout(Duration::from_millis(200));
+
+    assert!(matches!(minimizer.solve(), Err(qmc::Error::Timeout)));
+    assert!(!token.is_cancelled());
+}
+
+#[test]
+fn not_cancelled() {
+    let token = qmc::CancellationToken::new();
+    let (minterms, maxterms) = ([0, 5], [1, 3, 4, 6]);
+
+    let solutions = qmc::Minimizer::new(&["A", "B", "C"])
+        .minterms(&minterms)
+        .maxterms(&maxterms)
+        .cancellation_token(&token)
+        .solve()
+        .unwrap();
+
+    assert_eq!(solutions[0].to_string(), "(A ∧ C) ∨ (~A ∧ ~C)");
+}
+
+/// A function of 10 variables taking far longer than a second to minimize.
+fn hard_function() -> qmc::Minimizer {
+    let kinds = (0..1 << 10)
+        .map(|term: u32| (term.wrapping_mul(2654435761) >> 13) % 3)
+        .collect::<Vec<_>>();
+    let terms = |kind| {
+        (0..1 << 10)
+            .filter(|&term| kinds[term as usize] == kind)
+            .collect::<Vec<_>>()
+    };
+
+    qmc::Minimizer::new(&qmc::DEFAULT_VARIABLES[..10])
+        .minterms(&terms(0))
+        .maxterms(&terms(1))
+}
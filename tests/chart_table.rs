@@ -0,0 +1,92 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn text() {
+    let chart = qmc::prime_implicant_chart(
+        &qmc::DEFAULT_VARIABLES[..4],
+        &[0, 2, 5, 6, 7, 8, 10, 12, 13, 14, 15],
+        &[1, 3, 4, 9, 11],
+        qmc::SOP,
+    )
+    .unwrap();
+
+    assert_eq!(
+        chart.to_string(),
+        "       | 0 | 2 | 5 | 6 | 7 | 8 | 10 | 12 | 13 | 14 | 15 |\n\
+         * -0-0 | X | X |   |   |   | X |  X |    |    |    |    |\n\
+         * --10 |   | X |   | X |   |   |  X |    |    |  X |    |\n\
+         * -1-1 |   |   | X |   | X |   |    |    |  X |    |  X |\n\
+         ~ -11- |   |   |   | X | X |   |    |    |    |  X |  X |\n\
+         * 1--0 |   |   |   |   |   | X |  X |  X |    |  X |    |\n\
+         ~ 11-- |   |   |   |   |   |   |    |  X |  X |  X |  X |\n\
+         \x20      |   |   |   |   |   |   |    |    |    |  ~ |    |\n"
+    );
+}
+
+#[test]
+fn markdown() {
+    let chart = qmc::prime_implicant_chart(
+        &qmc::DEFAULT_VARIABLES[..4],
+        &[4, 8, 10, 11, 12, 15],
+        &[0, 1, 2, 3, 5, 6, 7, 13],
+        qmc::SOP,
+    )
+    .unwrap();
+
+    assert_eq!(
+        chart.to_markdown(),
+        "| Implicant | 4 | 8 | 10 | 11 | 12 | 15 |\n\
+         |:-|:-:|:-:|:-:|:-:|:-:|:-:|\n\
+         | **-100** | X |  |  |  | X |  |\n\
+         | ~~10--~~ |  | X | X | X |  |  |\n\
+         | **1--0** |  | X | X |  | X |  |\n\
+         | **1-1-** |  |  | X | X |  | X |\n"
+    );
+}
+
+#[test]
+fn html() {
+    let chart =
+        qmc::prime_implicant_chart(&qmc::DEFAULT_VARIABLES[..2], &[1, 3], &[0], qmc::POS).unwrap();
+
+    assert_eq!(
+        chart.to_html(),
+        "<table>\n\
+         \x20 <tr>\n\
+         \x20   <th>Implicant</th>\n\
+         \x20   <th>0</th>\n\
+         \x20 </tr>\n\
+         \x20 <tr class=\"essential\">\n\
+         \x20   <td>-0</td>\n\
+         \x20   <td>X</td>\n\
+         \x20 </tr>\n\
+         </table>\n"
+    );
+}
+
+#[test]
+fn html_dominance() {
+    let html = qmc::prime_implicant_chart(
+        &qmc::DEFAULT_VARIABLES[..4],
+        &[0, 2, 5, 6, 7, 8, 10, 12, 13, 14, 15],
+        &[1, 3, 4, 9, 11],
+        qmc::SOP,
+    )
+    .unwrap()
+    .to_html();
+
+    assert!(html.contains("    <th class=\"dominating\">14</th>\n"));
+    assert!(html.contains("  <tr class=\"dominated\">\n    <td>-11-</td>\n"));
+    assert!(html.contains("  <tr class=\"dominated\">\n    <td>11--</td>\n"));
+    assert!(!html.contains("<th class=\"dominated\">"));
+}
+
+#[test]
+fn empty() {
+    let chart =
+        qmc::prime_implicant_chart(&qmc::DEFAULT_VARIABLES[..2], &[], &[0, 1, 2, 3], qmc::SOP)
+            .unwrap();
+
+    assert!(chart.rows.is_empty());
+    assert!(chart.columns.is_empty());
+}
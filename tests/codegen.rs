@@ -0,0 +1,154 @@
+use quine_mccluskey as qmc;
+
+static VARIABLES: [&str; 3] = ["sel", "a", "b"];
+
+#[test]
+fn rust() {
+    let (sop, pos) = solutions();
+    let rust = qmc::RustCode::default();
+
+    assert_eq!(
+        rust.function("mux", &VARIABLES, &pos).unwrap(),
+        "pub fn mux(sel: bool, a: bool, b: bool) -> bool {\n    (sel || a) && (!sel || b)\n}\n"
+    );
+    assert_eq!(
+        rust.function("f", &VARIABLES, &qmc::Solution::One).unwrap(),
+        "pub fn f(_sel: bool, _a: bool, _b: bool) -> bool {\n    true\n}\n"
+    );
+
+    let solution = qmc::minimize(
+        &VARIABLES,
+        &[4, 5],
+        &[0, 1, 2, 3, 6, 7],
+        qmc::SOP,
+        false,
+        None,
+    )
+    .unwrap()
+    .pop()
+    .unwrap();
+
+    assert_eq!(
+        rust.function("f", &VARIABLES, &solution).unwrap(),
+        "pub fn f(sel: bool, a: bool, _b: bool) -> bool {\n    sel && !a\n}\n"
+    );
+
+    let rust = qmc::RustCode {
+        parameters: qmc::Parameters::Bits,
+    };
+
+    assert_eq!(
+        rust.function("mux", &VARIABLES, &sop).unwrap(),
+        "pub fn mux(x: u32) -> bool {\n    \
+         ((x >> 2) & 1 != 0 && x & 1 != 0) || ((x >> 2) & 1 == 0 && (x >> 1) & 1 != 0)\n}\n"
+    );
+    assert_eq!(
+        rust.function("f", &VARIABLES, &qmc::Solution::Zero)
+            .unwrap(),
+        "pub fn f(_x: u32) -> bool {\n    false\n}\n"
+    );
+}
+
+#[test]
+fn c() {
+    let (sop, pos) = solutions();
+
+    assert_eq!(
+        qmc::CCode::default()
+            .function("mux", &VARIABLES, &pos)
+            .unwrap(),
+        "static inline int mux(int sel, int a, int b) {\n    return (sel || a) && (!sel || b);\n}\n"
+    );
+    assert_eq!(
+        qmc::CCode::default()
+            .function("one", &[] as &[&str], &qmc::Solution::One)
+            .unwrap(),
+        "static inline int one(void) {\n    return 1;\n}\n"
+    );
+
+    let bits = qmc::CCode {
+        parameters: qmc::Parameters::Bits,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        bits.function("mux", &VARIABLES, &sop).unwrap(),
+        "static inline int mux(unsigned long x) {\n    \
+         return (((x >> 2) & 1) && (x & 1)) || (!((x >> 2) & 1) && ((x >> 1) & 1));\n}\n"
+    );
+
+    let bits_macro = qmc::CCode {
+        parameters: qmc::Parameters::Bits,
+        is_macro: true,
+    };
+
+    assert_eq!(
+        bits_macro.function("MUX", &VARIABLES, &sop).unwrap(),
+        "#define MUX(x) (((((x) >> 2) & 1) && ((x) & 1)) || (!(((x) >> 2) & 1) && (((x) >> 1) & 1)))\n"
+    );
+}
+
+#[test]
+fn identifiers() {
+    let variables = ["A", "type", "2x", "int"];
+    let solution = qmc::minimize(&variables, &[0, 15], &[1, 2, 4, 8], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let rust = qmc::RustCode::default()
+        .function("fn", &variables, &solution)
+        .unwrap();
+
+    assert!(rust.starts_with(
+        "#[allow(non_snake_case)]\npub fn fn_(A: bool, type_: bool, _2x: bool, int: bool) -> bool {"
+    ));
+
+    let c = qmc::CCode::default()
+        .function("a+b", &variables, &solution)
+        .unwrap();
+
+    assert!(c.starts_with("static inline int a_b(int A, int type, int _2x, int int_) {"));
+}
+
+#[test]
+#[should_panic(expected = "DuplicateVariables")]
+fn duplicate_variables() {
+    qmc::RustCode::default()
+        .function("f", &["a", "a"], &qmc::Solution::One)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "UnknownVariable")]
+fn unknown_variable() {
+    let (sop, _) = solutions();
+
+    qmc::CCode::default()
+        .function("f", &VARIABLES[..2], &sop)
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "InvalidVariableCount")]
+fn too_many_inputs() {
+    let variables = (0..27).map(|i| format!("x{}", i)).collect::<Vec<_>>();
+
+    qmc::CCode {
+        parameters: qmc::Parameters::Bits,
+        ..Default::default()
+    }
+    .function("f", &variables, &qmc::Solution::One)
+    .unwrap();
+}
+
+fn solutions() -> (qmc::Solution, qmc::Solution) {
+    let solve = |form| {
+        qmc::minimize(&VARIABLES, &[2, 3, 5, 7], &[0, 1, 4, 6], form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    (solve(qmc::SOP), solve(qmc::POS))
+}
@@ -0,0 +1,96 @@
+use quine_mccluskey as qmc;
+use quine_mccluskey::Solution;
+
+#[test]
+fn complement() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+
+    for seed in 0..20u32 {
+        let minterms = (0..16u32)
+            .filter(|term| {
+                term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                    .rotate_left(seed)
+                    % 3
+                    == 0
+            })
+            .collect::<Vec<_>>();
+        let maxterms = (0..16)
+            .filter(|term| !minterms.contains(term))
+            .collect::<Vec<_>>();
+
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = minimize(variables, &minterms, &maxterms, form);
+
+            for complement_form in [qmc::SOP, qmc::POS] {
+                let complement = solution.complement(complement_form).unwrap();
+                let expected = minimize(variables, &maxterms, &minterms, complement_form);
+
+                assert!(complement
+                    .verify_against(variables, &maxterms, &minterms)
+                    .unwrap()
+                    .is_equivalent());
+                assert_eq!(complement.literal_count(), expected.literal_count());
+                assert!(matches!(
+                    (&complement, complement_form),
+                    (Solution::SOP(_), qmc::SOP)
+                        | (Solution::POS(_), qmc::POS)
+                        | (Solution::One | Solution::Zero, _)
+                ));
+            }
+        }
+    }
+}
+
+#[test]
+fn complement_variable_order() {
+    let variables = ["sel", "a", "b"];
+    let solution = minimize(&variables, &[2, 3, 5, 7], &[0, 1, 4, 6], qmc::SOP);
+
+    assert_eq!(solution.to_string(), "(sel ∧ b) ∨ (~sel ∧ a)");
+    assert_eq!(
+        solution.complement(qmc::SOP).unwrap().to_string(),
+        "(sel ∧ ~b) ∨ (~sel ∧ ~a)"
+    );
+    assert_eq!(
+        solution.complement(qmc::POS).unwrap().to_string(),
+        "(~sel ∨ ~b) ∧ (sel ∨ ~a)"
+    );
+}
+
+#[test]
+fn complement_constants() {
+    let partial = minimize(&["a", "b"], &[3], &[0, 1, 2], qmc::POS);
+
+    assert!(matches!(
+        Solution::One.complement(qmc::SOP),
+        Ok(Solution::Zero)
+    ));
+    assert!(matches!(
+        Solution::Zero.complement(qmc::POS),
+        Ok(Solution::One)
+    ));
+    assert_eq!(partial.to_string(), "a ∧ b");
+    assert_eq!(partial.complement(qmc::POS).unwrap().to_string(), "~a ∨ ~b");
+}
+
+#[test]
+fn dual() {
+    let solution = minimize(&["a", "b", "c"], &[1, 3, 6, 7], &[0, 2, 4, 5], qmc::SOP);
+
+    assert_eq!(solution.dual().to_string(), "(a ∨ b) ∧ (~a ∨ c)");
+    assert_eq!(solution.dual().dual().to_string(), solution.to_string());
+    assert!(matches!(Solution::One.dual(), Solution::Zero));
+    assert!(matches!(Solution::Zero.dual(), Solution::One));
+}
+
+fn minimize<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: qmc::Form,
+) -> Solution {
+    qmc::minimize(variables, minterms, maxterms, form, false, None)
+        .unwrap()
+        .pop()
+        .unwrap()
+}
@@ -0,0 +1,80 @@
+use std::time::Duration;
+
+use quine_mccluskey as qmc;
+use quine_mccluskey::{CostMetric, Solution};
+
+#[test]
+fn cost() {
+    let solution = qmc::minimize(
+        &qmc::DEFAULT_VARIABLES[..4],
+        &[0, 1, 2, 3, 12, 14],
+        &[4, 5, 6, 7, 8, 9, 10, 11, 13, 15],
+        qmc::SOP,
+        false,
+        None,
+    )
+    .unwrap()
+    .pop()
+    .unwrap();
+
+    assert_eq!(solution.to_string(), "(~A ∧ ~B) ∨ (A ∧ B ∧ ~D)");
+    assert_eq!(solution.cost(CostMetric::Literals), 5);
+    assert_eq!(solution.cost(CostMetric::Terms), 2);
+    assert_eq!(solution.cost(CostMetric::GateInputs), 7);
+
+    for metric in [
+        CostMetric::Literals,
+        CostMetric::Terms,
+        CostMetric::GateInputs,
+    ] {
+        assert_eq!(Solution::One.cost(metric), 0);
+        assert_eq!(Solution::Zero.cost(metric), 0);
+    }
+}
+
+#[test]
+fn single_term() {
+    let solution = qmc::minimize_maxterms(&["a", "b"], &[0], &[], false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(solution.to_string(), "a ∨ b");
+    assert_eq!(solution.cost(CostMetric::Terms), 1);
+    assert_eq!(solution.cost(CostMetric::GateInputs), 2);
+}
+
+#[test]
+fn both_forms() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    // C ∧ (A ∨ B) is cheaper than (A ∧ C) ∨ (B ∧ C).
+    let minterms = [3, 5, 7];
+    let maxterms = [0, 1, 2, 4, 6];
+
+    for timeout in [None, Some(Duration::from_secs(10))] {
+        let both = qmc::minimize_both(variables, &minterms, &maxterms, false, timeout).unwrap();
+
+        assert_eq!(both.sop.len(), 1);
+        assert_eq!(both.pos.len(), 1);
+        assert_eq!(both.sop[0].to_string(), "(A ∧ C) ∨ (B ∧ C)");
+        assert_eq!(both.pos[0].to_string(), "C ∧ (A ∨ B)");
+        assert_eq!(both.costs(CostMetric::Literals), (4, 3));
+        assert_eq!(both.costs(CostMetric::Terms), (2, 2));
+        assert_eq!(both.cheaper_form(CostMetric::Literals), qmc::POS);
+        assert_eq!(both.cheaper_form(CostMetric::Terms), qmc::SOP);
+        assert_eq!(
+            both.cheaper(CostMetric::GateInputs).to_string(),
+            "C ∧ (A ∨ B)"
+        );
+    }
+}
+
+#[test]
+fn both_forms_errors() {
+    for timeout in [None, Some(Duration::from_secs(10))] {
+        assert!(matches!(
+            qmc::minimize_both(&["a"], &[0], &[0], false, timeout),
+            Err(qmc::Error::TermConflict(_))
+        ));
+    }
+}
@@ -0,0 +1,123 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn parity() {
+    for variable_count in 1..=6 {
+        let minterms = (0..1 << variable_count)
+            .filter(|term: &u32| term.count_ones() % 2 == 1)
+            .collect::<Vec<_>>();
+        let esop = minimize(variable_count, &minterms, &[]);
+
+        assert_eq!(esop.product_count(), variable_count);
+        assert_eq!(esop.literal_count(), variable_count);
+    }
+}
+
+#[test]
+fn adder() {
+    let variables = ["a", "b", "c"];
+    let sum = qmc::minimize_esop(&variables, &[1, 2, 4, 7], &[]).unwrap();
+    let carry = qmc::minimize_esop(&variables, &[3, 5, 6, 7], &[]).unwrap();
+
+    assert_eq!(sum.to_string(), "a ⊕ b ⊕ c");
+    assert_eq!(carry.product_count(), 3);
+    assert_eq!(carry.literal_count(), 6);
+    assert_equivalent(&carry, 3, &[3, 5, 6, 7], &[]);
+}
+
+#[test]
+fn dont_cares() {
+    // A ⊕ B once the don't cares are chosen well.
+    let esop = minimize(3, &[2, 4], &[3, 5]);
+
+    assert_eq!(esop.to_string(), "A ⊕ B");
+    assert_equivalent(&esop, 3, &[2, 4], &[3, 5]);
+}
+
+#[test]
+fn constants() {
+    let zero = minimize(2, &[], &[]);
+    let one = minimize(2, &[0, 1, 2, 3], &[]);
+    let negation = minimize(2, &[0, 1, 2], &[]);
+
+    assert_eq!(zero.to_string(), "0");
+    assert_eq!(one.to_string(), "1");
+    assert_eq!(negation.product_count(), 2);
+    assert_equivalent(&negation, 2, &[0, 1, 2], &[]);
+    assert!(one.products()[0].is_empty());
+}
+
+#[test]
+fn display() {
+    let esop = minimize(3, &[3, 4, 5, 6], &[]);
+
+    assert_eq!(esop.to_string(), "A ⊕ (B ∧ C)");
+    assert_eq!(
+        esop.products()
+            .iter()
+            .map(|variables| variables
+                .iter()
+                .map(|variable| variable.name.as_str())
+                .collect::<Vec<_>>())
+            .collect::<Vec<_>>(),
+        [vec!["A"], vec!["B", "C"]]
+    );
+}
+
+#[test]
+fn equivalence() {
+    for seed in 0..30u32 {
+        let terms = (0..32u32)
+            .map(|term| {
+                term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                    .rotate_left(seed)
+                    % 5
+            })
+            .collect::<Vec<_>>();
+        let minterms = (0..32)
+            .filter(|&term| terms[term as usize] < 2)
+            .collect::<Vec<_>>();
+        let dont_cares = (0..32)
+            .filter(|&term| terms[term as usize] == 2)
+            .collect::<Vec<_>>();
+
+        let esop = minimize(5, &minterms, &dont_cares);
+
+        assert!(esop.product_count() <= minterms.len());
+        assert_equivalent(&esop, 5, &minterms, &dont_cares);
+    }
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn conflict() {
+    minimize(2, &[1, 2], &[2]);
+}
+
+fn minimize(variable_count: usize, minterms: &[u32], dont_cares: &[u32]) -> qmc::Esop {
+    qmc::minimize_esop(
+        &qmc::DEFAULT_VARIABLES[..variable_count],
+        minterms,
+        dont_cares,
+    )
+    .unwrap()
+}
+
+fn assert_equivalent(
+    esop: &qmc::Esop,
+    variable_count: usize,
+    minterms: &[u32],
+    dont_cares: &[u32],
+) {
+    for term in 0..1 << variable_count {
+        if !dont_cares.contains(&term) {
+            assert_eq!(
+                esop.evaluate(term),
+                minterms.contains(&term),
+                "{} at {}",
+                esop,
+                term
+            );
+        }
+    }
+}
@@ -0,0 +1,127 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn common_cube() {
+    let solution = minimize(5, &[0b11100, 0b11010, 0b11110, 0b11101], qmc::SOP);
+
+    assert_eq!(solution.to_string(), "(A ∧ B ∧ C ∧ ~D) ∨ (A ∧ B ∧ D ∧ ~E)");
+    assert_factored(&solution, "A ∧ B ∧ ((C ∧ ~D) ∨ (D ∧ ~E))", 8, 6);
+}
+
+#[test]
+fn kernels() {
+    // (A ∨ B ∨ C) ∧ (D ∨ E)
+    let minterms = (0..32)
+        .filter(|term| term & 0b11100 != 0 && term & 0b00011 != 0)
+        .collect::<Vec<_>>();
+
+    assert_factored(
+        &minimize(5, &minterms, qmc::SOP),
+        "(A ∨ B ∨ C) ∧ (D ∨ E)",
+        12,
+        5,
+    );
+
+    // (A ∧ (B ∨ C)) ∨ D
+    let minterms = (0..16)
+        .filter(|term| term & 0b1000 != 0 && term & 0b0110 != 0 || term & 0b0001 != 0)
+        .collect::<Vec<_>>();
+
+    assert_factored(&minimize(4, &minterms, qmc::SOP), "(A ∧ (B ∨ C)) ∨ D", 5, 4);
+}
+
+#[test]
+fn pos() {
+    // (A ∧ B) ∨ (C ∧ D)
+    let minterms = (0..16)
+        .filter(|term| term & 0b1100 == 0b1100 || term & 0b0011 == 0b0011)
+        .collect::<Vec<_>>();
+    let solution = minimize(4, &minterms, qmc::POS);
+
+    assert_eq!(
+        solution.to_string(),
+        "(A ∨ C) ∧ (A ∨ D) ∧ (B ∨ C) ∧ (B ∨ D)"
+    );
+    assert_factored(&solution, "(A ∧ B) ∨ (C ∧ D)", 8, 4);
+}
+
+#[test]
+fn unfactorable() {
+    let solution = minimize(2, &[1, 2], qmc::SOP);
+
+    assert_factored(&solution, "(A ∧ ~B) ∨ (~A ∧ B)", 4, 4);
+    assert_factored(&qmc::Solution::One, "1", 0, 0);
+    assert_factored(&qmc::Solution::Zero, "0", 0, 0);
+}
+
+#[test]
+fn style() {
+    let solution = minimize(4, &[13, 14], qmc::SOP);
+    let factored = solution.factor();
+
+    assert_eq!(factored.format(&qmc::Style::textbook()), "AB(CD' + C'D)");
+    assert_eq!(
+        factored.format(&qmc::Style::python()),
+        "A and B and (C and not D or not C and D)"
+    );
+}
+
+#[test]
+fn equivalence() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+
+    for seed in 0..20u32 {
+        let minterms = (0..32u32)
+            .filter(|term| {
+                term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                    .rotate_left(seed)
+                    % 3
+                    == 0
+            })
+            .collect::<Vec<_>>();
+
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = minimize(5, &minterms, form);
+            let factored = solution.factor();
+
+            assert!(factored.literal_count() <= solution.literal_count());
+
+            for term in 0..32 {
+                assert_eq!(
+                    factored.evaluate(variables, term),
+                    minterms.contains(&term),
+                    "{} / {} at {}",
+                    solution,
+                    factored,
+                    term
+                );
+            }
+        }
+    }
+}
+
+fn minimize(variable_count: usize, minterms: &[u32], form: qmc::Form) -> qmc::Solution {
+    let maxterms = (0..1 << variable_count)
+        .filter(|term| !minterms.contains(term))
+        .collect::<Vec<_>>();
+
+    qmc::minimize(
+        &qmc::DEFAULT_VARIABLES[..variable_count],
+        minterms,
+        &maxterms,
+        form,
+        false,
+        None,
+    )
+    .unwrap()
+    .pop()
+    .unwrap()
+}
+
+fn assert_factored(solution: &qmc::Solution, expected: &str, before: usize, after: usize) {
+    let factored = solution.factor();
+
+    assert_eq!(factored.to_string(), expected);
+    assert_eq!(solution.literal_count(), before);
+    assert_eq!(factored.literal_count(), after);
+}
@@ -0,0 +1,211 @@
+use quine_mccluskey as qmc;
+use quine_mccluskey::{Solution, Transitions};
+
+static VARIABLES: [&str; 3] = ["sel", "a", "b"];
+static MINTERMS: [u32; 4] = [2, 3, 5, 7];
+static MAXTERMS: [u32; 4] = [0, 1, 4, 6];
+
+#[test]
+fn single_variable() {
+    let sop = minimize(
+        &VARIABLES,
+        &MINTERMS,
+        &MAXTERMS,
+        qmc::SOP,
+        &Transitions::SingleVariable,
+    );
+    let pos = minimize(
+        &VARIABLES,
+        &MINTERMS,
+        &MAXTERMS,
+        qmc::POS,
+        &Transitions::SingleVariable,
+    );
+
+    assert_eq!(sop.to_string(), "(sel ∧ b) ∨ (~sel ∧ a) ∨ (a ∧ b)");
+    assert_eq!(pos.to_string(), "(sel ∨ a) ∧ (~sel ∨ b) ∧ (a ∨ b)");
+}
+
+#[test]
+fn listed() {
+    // Only the transitions that don't involve sel changing with a = b = 1 don't need the consensus term.
+    let solution = minimize(
+        &VARIABLES,
+        &MINTERMS,
+        &MAXTERMS,
+        qmc::SOP,
+        &Transitions::Listed(vec![(2, 3), (5, 7)]),
+    );
+
+    assert_eq!(solution.to_string(), "(sel ∧ b) ∨ (~sel ∧ a)");
+
+    let solution = minimize(
+        &VARIABLES,
+        &MINTERMS,
+        &MAXTERMS,
+        qmc::SOP,
+        &Transitions::Listed(vec![(3, 7)]),
+    );
+
+    assert_eq!(solution.to_string(), "(sel ∧ b) ∨ (~sel ∧ a) ∨ (a ∧ b)");
+}
+
+#[test]
+fn multiple_variables() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    // (A ∧ ~C) ∨ (B ∧ C) ∨ (A ∧ D), with transitions changing C and D or B and C at once.
+    let minterms = (0..16)
+        .filter(|term| {
+            term & 0b1010 == 0b1000 || term & 0b0110 == 0b0110 || term & 0b1001 == 0b1001
+        })
+        .collect::<Vec<_>>();
+    let maxterms = (0..16)
+        .filter(|term| !minterms.contains(term))
+        .collect::<Vec<_>>();
+    let transitions = Transitions::Listed(vec![(0b1100, 0b1111), (0b1001, 0b1111)]);
+
+    let solution = minimize(variables, &minterms, &maxterms, qmc::SOP, &transitions);
+
+    assert_eq!(
+        solution.to_string(),
+        "(A ∧ B) ∨ (A ∧ ~C) ∨ (A ∧ D) ∨ (B ∧ C)"
+    );
+    assert!(covers(&solution, 4, 0b1100, 0b1111));
+    assert!(covers(&solution, 4, 0b1001, 0b1111));
+    assert!(solution
+        .verify_against(variables, &minterms, &maxterms)
+        .unwrap()
+        .is_equivalent());
+}
+
+#[test]
+fn all_transitions_covered() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+
+    for seed in 0..20u32 {
+        let terms = (0..16u32)
+            .map(|term| {
+                term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                    .rotate_left(seed)
+                    % 5
+            })
+            .collect::<Vec<_>>();
+        let minterms = (0..16)
+            .filter(|&t| terms[t as usize] < 2)
+            .collect::<Vec<_>>();
+        let maxterms = (0..16)
+            .filter(|&t| terms[t as usize] > 2)
+            .collect::<Vec<_>>();
+
+        for (form, on) in [(qmc::SOP, &minterms), (qmc::POS, &maxterms)] {
+            let solution = minimize(
+                variables,
+                &minterms,
+                &maxterms,
+                form,
+                &Transitions::SingleVariable,
+            );
+
+            assert!(solution
+                .verify_against(variables, &minterms, &maxterms)
+                .unwrap()
+                .is_equivalent());
+
+            for &term in on.iter() {
+                for bit in 0..4 {
+                    if on.contains(&(term ^ 1 << bit)) {
+                        assert!(
+                            covers(&solution, 4, term, term ^ 1 << bit),
+                            "{} at {}",
+                            solution,
+                            term
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn invalid_transitions() {
+    for transition in [(2, 0), (8, 3), (3, 5)] {
+        let result = qmc::minimize_hazard_free(
+            &VARIABLES,
+            &MINTERMS,
+            &MAXTERMS,
+            qmc::SOP,
+            &Transitions::Listed(vec![transition]),
+            false,
+            None,
+        );
+
+        assert!(matches!(
+            result,
+            Err(qmc::Error::InvalidTransition(from, to)) if (from, to) == transition
+        ));
+    }
+
+    // 0 -> 4 is a transition between maxterms.
+    assert!(qmc::minimize_hazard_free(
+        &VARIABLES,
+        &MINTERMS,
+        &MAXTERMS,
+        qmc::POS,
+        &Transitions::Listed(vec![(0, 4)]),
+        false,
+        None,
+    )
+    .is_ok());
+}
+
+fn minimize<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: qmc::Form,
+    transitions: &Transitions,
+) -> Solution {
+    qmc::minimize_hazard_free(
+        variables,
+        minterms,
+        maxterms,
+        form,
+        transitions,
+        false,
+        None,
+    )
+    .unwrap()
+    .pop()
+    .unwrap()
+}
+
+/// Returns whether a single term of the solution keeps its value over all terms between `from` and `to`.
+fn covers(solution: &Solution, variable_count: usize, from: u32, to: u32) -> bool {
+    let (terms, is_sop) = match solution {
+        Solution::SOP(terms) => (terms, true),
+        Solution::POS(terms) => (terms, false),
+        _ => return true,
+    };
+    let cube = (0..1 << variable_count)
+        .filter(|term| (term ^ from) & !(from ^ to) == 0)
+        .collect::<Vec<u32>>();
+    let literal = |variable: &qmc::Variable, term: u32| {
+        let position = qmc::DEFAULT_VARIABLES
+            .iter()
+            .position(|&name| name == variable.name)
+            .unwrap();
+
+        (term >> (variable_count - position - 1) & 1 == 1) != variable.is_negated
+    };
+
+    terms.iter().any(|term| {
+        cube.iter().all(|&t| {
+            if is_sop {
+                term.iter().all(|variable| literal(variable, t))
+            } else {
+                !term.iter().any(|variable| literal(variable, t))
+            }
+        })
+    })
+}
@@ -0,0 +1,101 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn expression() {
+    let (sop, pos) = solutions();
+
+    let overline = qmc::Latex::default();
+    assert_eq!(
+        overline.expression(&sop),
+        "B + A\\overline{C} + \\overline{A}C"
+    );
+    assert_eq!(
+        overline.expression(&pos),
+        "(A + B + C)(\\overline{A} + B + \\overline{C})"
+    );
+
+    let logic = qmc::Latex {
+        notation: qmc::LatexNotation::Logic,
+        ..Default::default()
+    };
+    assert_eq!(
+        logic.expression(&sop),
+        "B \\lor (A \\land \\lnot C) \\lor (\\lnot A \\land C)"
+    );
+    assert_eq!(
+        logic.expression(&pos),
+        "(A \\lor B \\lor C) \\land (\\lnot A \\lor B \\lor \\lnot C)"
+    );
+
+    assert_eq!(overline.expression(&qmc::Solution::One), "1");
+    assert_eq!(overline.expression(&qmc::Solution::Zero), "0");
+}
+
+#[test]
+fn equation() {
+    let (sop, pos) = solutions();
+
+    let latex = qmc::Latex {
+        terms_per_line: Some(2),
+        ..Default::default()
+    };
+    assert_eq!(
+        latex.equation("F", &sop),
+        "\\begin{align*}\n\
+         F &= B + A\\overline{C} \\\\\n\
+         &\\quad + \\overline{A}C\n\
+         \\end{align*}"
+    );
+    assert_eq!(
+        latex.equation("F", &pos),
+        "F = (A + B + C)(\\overline{A} + B + \\overline{C})"
+    );
+    assert_eq!(
+        qmc::Latex::default().equation("F", &sop),
+        "F = B + A\\overline{C} + \\overline{A}C"
+    );
+}
+
+#[test]
+fn escaping() {
+    let solution = qmc::minimize(&["x_1", "y"], &[1], &[0, 2, 3], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        qmc::Latex::default().equation("out_%", &solution),
+        "\\mathit{out\\_\\%} = \\overline{\\mathit{x\\_1}}y"
+    );
+}
+
+#[test]
+fn specification() {
+    assert_eq!(
+        qmc::Latex::specification(qmc::SOP, &[5, 0], &[7, 2]),
+        "\\sum m(0,5) + d(2,7)"
+    );
+    assert_eq!(
+        qmc::Latex::specification(qmc::SOP, &[0, 5], &[]),
+        "\\sum m(0,5)"
+    );
+    assert_eq!(
+        qmc::Latex::specification(qmc::POS, &[1, 3, 4, 6], &[2, 7]),
+        "\\prod M(1,3,4,6) \\cdot D(2,7)"
+    );
+}
+
+fn solutions() -> (qmc::Solution, qmc::Solution) {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    let minterms = &[4, 6, 7, 1, 2, 3];
+    let maxterms = &[5, 0];
+
+    let solve = |form| {
+        qmc::minimize(variables, minterms, maxterms, form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    (solve(qmc::SOP), solve(qmc::POS))
+}
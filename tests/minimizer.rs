@@ -0,0 +1,184 @@
+use std::time::Duration;
+
+use quine_mccluskey as qmc;
+use rand::Rng;
+
+#[test]
+fn random_edits() {
+    let mut rng = rand::rng();
+
+    for variable_count in 1..=5 {
+        let variables = &qmc::DEFAULT_VARIABLES[..variable_count];
+
+        for form in [qmc::SOP, qmc::POS] {
+            let mut minimizer = qmc::Minimizer::new(variables).form(form);
+            let mut kinds = vec![2; 1 << variable_count];
+
+            for edit in 0..200 {
+                let term = rng.random_range(0..kinds.len());
+                let kind = rng.random_range(0..3);
+
+                kinds[term] = kind;
+
+                match kind {
+                    0 => minimizer.add_minterm(term as u32),
+                    1 => minimizer.add_maxterm(term as u32),
+                    _ => minimizer.add_dont_care(term as u32),
+                }
+                .unwrap();
+
+                // Solve now and then, so that some edits pile up between solving.
+                if edit % 3 != 0 {
+                    continue;
+                }
+
+                let minterms = terms(&kinds, 0);
+                let maxterms = terms(&kinds, 1);
+
+                for find_all_solutions in [false, true] {
+                    minimizer = minimizer.all_solutions(find_all_solutions);
+
+                    assert_eq!(
+                        to_strings(minimizer.solve().unwrap()),
+                        to_strings(
+                            qmc::minimize(
+                                variables,
+                                &minterms,
+                                &maxterms,
+                                form,
+                                find_all_solutions,
+                                None
+                            )
+                            .unwrap()
+                        ),
+                        "{:?} minterms: {:?}, maxterms: {:?}",
+                        form,
+                        minterms,
+                        maxterms
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn timeout() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let mut minimizer = qmc::Minimizer::new(variables)
+        .form(qmc::POS)
+        .all_solutions(true)
+        .timeout(Duration::from_secs(10));
+    let (mut minterms, maxterms) = (vec![3, 4, 6, 7, 11, 12, 14, 15], [0, 1, 2, 5, 8, 9, 10]);
+
+    for &term in &minterms {
+        minimizer.add_minterm(term).unwrap();
+    }
+
+    for &term in &maxterms {
+        minimizer.add_maxterm(term).unwrap();
+    }
+
+    for _ in 0..2 {
+        assert_eq!(
+            to_strings(minimizer.solve().unwrap()),
+            to_strings(
+                qmc::minimize(variables, &minterms, &maxterms, qmc::POS, true, None).unwrap()
+            )
+        );
+
+        minimizer.add_dont_care(minterms.pop().unwrap()).unwrap();
+    }
+}
+
+#[test]
+fn builder() {
+    let variables = ["A", "B", "C"];
+    let (minterms, maxterms, dont_cares) = ([1, 2, 5], [0, 4, 6], [3]);
+
+    let solve =
+        |minimizer: qmc::Minimizer| to_strings(minimizer.all_solutions(true).solve().unwrap());
+
+    assert_eq!(
+        solve(
+            qmc::Minimizer::new(&variables)
+                .minterms(&minterms)
+                .dont_cares(&dont_cares)
+        ),
+        to_strings(qmc::minimize_minterms(&variables, &minterms, &dont_cares, true, None).unwrap())
+    );
+    assert_eq!(
+        solve(
+            qmc::Minimizer::new(&variables)
+                .maxterms(&maxterms)
+                .dont_cares(&dont_cares)
+                .form(qmc::POS)
+        ),
+        to_strings(qmc::minimize_maxterms(&variables, &maxterms, &dont_cares, true, None).unwrap())
+    );
+
+    // With all three listed, term 7 is a don't care.
+    assert_eq!(
+        solve(
+            qmc::Minimizer::new(&variables)
+                .minterms(&minterms)
+                .maxterms(&maxterms)
+                .dont_cares(&dont_cares)
+        ),
+        to_strings(qmc::minimize(&variables, &minterms, &maxterms, qmc::SOP, true, None).unwrap())
+    );
+
+    // Switching the form keeps the terms.
+    let mut minimizer = qmc::Minimizer::new(&variables)
+        .minterms(&minterms)
+        .maxterms(&maxterms);
+
+    minimizer.solve().unwrap();
+    minimizer = minimizer.form(qmc::POS);
+
+    assert_eq!(
+        to_strings(minimizer.solve().unwrap()),
+        to_strings(qmc::minimize(&variables, &minterms, &maxterms, qmc::POS, false, None).unwrap())
+    );
+
+    // Listing terms again starts over.
+    minimizer = minimizer.minterms(&[7]);
+
+    assert_eq!(minimizer.solve().unwrap()[0].to_string(), "1");
+}
+
+#[test]
+fn errors() {
+    assert!(matches!(
+        qmc::Minimizer::new(&["A", "A"]).solve(),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+    assert!(matches!(
+        qmc::Minimizer::new(&["A", "B"])
+            .maxterms(&[1])
+            .dont_cares(&[1, 2])
+            .solve(),
+        Err(qmc::Error::TermConflict(_))
+    ));
+
+    let mut minimizer = qmc::Minimizer::new(&["A", "B"]);
+
+    assert!(matches!(
+        minimizer.add_minterm(4),
+        Err(qmc::Error::TermOutOfBounds {
+            variable_count: 2,
+            ..
+        })
+    ));
+    assert_eq!(minimizer.solve().unwrap()[0].to_string(), "0");
+}
+
+fn terms(kinds: &[u32], kind: u32) -> Vec<u32> {
+    (0..kinds.len() as u32)
+        .filter(|&term| kinds[term as usize] == kind)
+        .collect()
+}
+
+fn to_strings(solutions: Vec<qmc::Solution>) -> Vec<String> {
+    solutions.iter().map(ToString::to_string).collect()
+}
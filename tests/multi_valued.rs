@@ -0,0 +1,329 @@
+use std::time::{Duration, Instant};
+
+use quine_mccluskey as qmc;
+use quine_mccluskey::{MultiValuedSolution, MultiValuedVariable};
+
+#[test]
+fn set_literals() {
+    let variables = variables();
+    // Run with any opcode class, or Idle with a memory opcode class.
+    let minterms = (0..30)
+        .filter(|&term| {
+            let (mode, class, _) = values(term);
+            mode == 1 || mode == 0 && (class == 1 || class == 2)
+        })
+        .collect::<Vec<_>>();
+
+    let solution = qmc::minimize_multi_valued(&variables, &minterms, &[], None).unwrap();
+
+    assert_eq!(
+        solution.to_string(),
+        "(Mode ∈ {Idle, Run} ∧ Class ∈ {Load, Store}) ∨ Mode = Run"
+    );
+    assert_equivalent(&solution, &variables, &minterms, &[]);
+}
+
+#[test]
+fn dont_cares() {
+    let variables = variables();
+    let minterms = [
+        term(&variables, ["Idle", "Load", "1"]),
+        term(&variables, ["Run", "Load", "1"]),
+    ];
+    let dont_cares = [term(&variables, ["Halt", "Load", "1"])];
+
+    let solution = qmc::minimize_multi_valued(&variables, &minterms, &dont_cares, None).unwrap();
+
+    assert_eq!(solution.to_string(), "Class = Load ∧ x = 1");
+    assert_equivalent(&solution, &variables, &minterms, &dont_cares);
+}
+
+#[test]
+fn constants() {
+    let variables = variables();
+
+    assert_eq!(
+        qmc::minimize_multi_valued(&variables, &[], &[1, 2], None).unwrap(),
+        MultiValuedSolution::Zero
+    );
+    assert_eq!(
+        qmc::minimize_multi_valued(&variables, &[0, 1, 2], &(3..30).collect::<Vec<_>>(), None)
+            .unwrap(),
+        MultiValuedSolution::One
+    );
+}
+
+#[test]
+fn binary() {
+    let names = &qmc::DEFAULT_VARIABLES[..4];
+    let variables = names
+        .iter()
+        .map(|&name| MultiValuedVariable::binary(name))
+        .collect::<Vec<_>>();
+
+    for seed in 0..20u32 {
+        let terms = (0..16u32)
+            .map(|term| {
+                term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                    .rotate_left(seed)
+                    % 5
+            })
+            .collect::<Vec<_>>();
+        let minterms = (0..16)
+            .filter(|&t| terms[t as usize] < 2)
+            .collect::<Vec<_>>();
+        let dont_cares = (0..16)
+            .filter(|&t| terms[t as usize] == 2)
+            .collect::<Vec<_>>();
+
+        let solution =
+            qmc::minimize_multi_valued(&variables, &minterms, &dont_cares, None).unwrap();
+        let expected = qmc::minimize_minterms(names, &minterms, &dont_cares, false, None)
+            .unwrap()
+            .pop()
+            .unwrap();
+
+        let (products, literals) = match &solution {
+            MultiValuedSolution::SOP(products) => {
+                (products.len(), products.iter().map(Vec::len).sum())
+            }
+            _ => (0, 0),
+        };
+
+        assert_eq!(
+            products,
+            expected.cost(qmc::CostMetric::Terms),
+            "{}",
+            expected
+        );
+        assert_eq!(literals, expected.literal_count(), "{}", expected);
+        assert_equivalent(&solution, &variables, &minterms, &dont_cares);
+    }
+}
+
+#[test]
+fn random() {
+    let variables = variables();
+
+    for seed in 0..10u32 {
+        let terms = (0..30u32)
+            .map(|term| {
+                term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                    .rotate_left(seed)
+                    % 4
+            })
+            .collect::<Vec<_>>();
+        let minterms = (0..30)
+            .filter(|&t| terms[t as usize] < 2)
+            .collect::<Vec<_>>();
+        let dont_cares = (0..30)
+            .filter(|&t| terms[t as usize] == 2)
+            .collect::<Vec<_>>();
+
+        let solution =
+            qmc::minimize_multi_valued(&variables, &minterms, &dont_cares, None).unwrap();
+
+        assert_equivalent(&solution, &variables, &minterms, &dont_cares);
+    }
+}
+
+#[test]
+fn max_value_count() {
+    let values = (0..qmc::MAX_VALUE_COUNT)
+        .map(|value| format!("v{}", value))
+        .collect::<Vec<_>>();
+    let variables = [
+        MultiValuedVariable::new("State", &values),
+        MultiValuedVariable::binary("x"),
+    ];
+    let minterms = [1, 62, 63];
+
+    let solution = qmc::minimize_multi_valued(&variables, &minterms, &[], None).unwrap();
+
+    assert_eq!(
+        solution.to_string(),
+        "(State ∈ {v0, v31} ∧ x = 1) ∨ State = v31"
+    );
+    assert_equivalent(&solution, &variables, &minterms, &[]);
+}
+
+#[test]
+fn sixteen_values() {
+    let values = (0..16)
+        .map(|value| format!("v{}", value))
+        .collect::<Vec<_>>();
+    let variables = [
+        MultiValuedVariable::new("State", &values),
+        MultiValuedVariable::binary("x"),
+    ];
+    // Every set of states with x = 1 is an implicant, but only the full set is prime.
+    let minterms = (0..32).filter(|term| term % 2 == 1).collect::<Vec<_>>();
+
+    let start = Instant::now();
+    let solution = qmc::minimize_multi_valued(&variables, &minterms, &[], None).unwrap();
+
+    assert!(start.elapsed() < Duration::from_secs(1));
+    assert_eq!(solution.to_string(), "x = 1");
+    assert_equivalent(&solution, &variables, &minterms, &[]);
+}
+
+#[test]
+fn timeout() {
+    let values = ["a", "b", "c", "d", "e", "f"];
+    let variables = [
+        MultiValuedVariable::new("A", &values),
+        MultiValuedVariable::new("B", &values),
+        MultiValuedVariable::new("C", &values),
+    ];
+    // A dense pseudo-random function has too many ways to cover it to search them in time.
+    let minterms = (0..216u32)
+        .filter(|term| term.wrapping_mul(2_654_435_761) >> 16 & 1 == 1)
+        .collect::<Vec<_>>();
+
+    let start = Instant::now();
+
+    assert!(matches!(
+        qmc::minimize_multi_valued(&variables, &minterms, &[], Some(Duration::from_millis(100))),
+        Err(qmc::Error::Timeout)
+    ));
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[test]
+fn errors() {
+    let mut variables = variables();
+
+    assert_eq!(
+        qmc::minimize_multi_valued(&variables, &[30], &[], None)
+            .unwrap_err()
+            .to_string(),
+        "Terms out of bounds: {30} (expected < 30 for 3 variables)"
+    );
+    assert!(matches!(
+        qmc::minimize_multi_valued(&variables, &[30], &[], None),
+        Err(qmc::Error::TermOutOfBounds {
+            variable_count: 3,
+            term_count: 30,
+            ..
+        })
+    ));
+    assert!(matches!(
+        qmc::minimize_multi_valued(&variables, &[1], &[1], None),
+        Err(qmc::Error::TermConflict(_))
+    ));
+
+    variables[1] = MultiValuedVariable::new("Class", &["Alu"]);
+
+    assert!(matches!(
+        qmc::minimize_multi_valued(&variables, &[1], &[], None),
+        Err(qmc::Error::InvalidValues(name)) if name == "Class"
+    ));
+
+    variables[1] = MultiValuedVariable::new("Class", &["Alu", "Alu"]);
+
+    assert!(matches!(
+        qmc::minimize_multi_valued(&variables, &[1], &[], None),
+        Err(qmc::Error::InvalidValues(_))
+    ));
+
+    let values = (0..qmc::MAX_VALUE_COUNT)
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+    let mut many_variables = (0..6)
+        .map(|i| MultiValuedVariable::new(&format!("v{}", i), &values))
+        .collect::<Vec<_>>();
+    many_variables.push(MultiValuedVariable::new("v6", &values[..4]));
+
+    // 32^6 * 4 = 2^32 combinations of values.
+    assert!(qmc::minimize_multi_valued(&many_variables, &[1], &[], None).is_ok());
+
+    many_variables[6] = MultiValuedVariable::new("v6", &values);
+
+    // 32^7 = 2^35 combinations of values can't be numbered by u32 terms.
+    assert!(matches!(
+        qmc::minimize_multi_valued(&many_variables, &[1], &[], None),
+        Err(qmc::Error::InvalidValues(name)) if name == "v6"
+    ));
+
+    variables[1] = MultiValuedVariable::binary("Mode");
+
+    assert!(matches!(
+        qmc::minimize_multi_valued(&variables, &[1], &[], None),
+        Err(qmc::Error::DuplicateVariables(_))
+    ));
+}
+
+#[test]
+fn terms() {
+    let variables = variables();
+
+    assert_eq!(term(&variables, ["Idle", "Alu", "0"]), 0);
+    assert_eq!(term(&variables, ["Run", "Store", "1"]), 15);
+    assert_eq!(term(&variables, ["Halt", "Other", "1"]), 29);
+    assert_eq!(
+        MultiValuedVariable::term(&variables, &["Run", "Load"]),
+        None
+    );
+    assert_eq!(
+        MultiValuedVariable::term(&variables, &["Run", "Load", "2"]),
+        None
+    );
+}
+
+/// `Mode ∈ {Idle, Run, Halt}`, `Class ∈ {Alu, Load, Store, Branch, Other}` and `x ∈ {0, 1}`, 30 terms in total.
+fn variables() -> Vec<MultiValuedVariable> {
+    vec![
+        MultiValuedVariable::new("Mode", &["Idle", "Run", "Halt"]),
+        MultiValuedVariable::new("Class", &["Alu", "Load", "Store", "Branch", "Other"]),
+        MultiValuedVariable::binary("x"),
+    ]
+}
+
+fn values(term: u32) -> (u32, u32, u32) {
+    (term / 10, term / 2 % 5, term % 2)
+}
+
+fn term(variables: &[MultiValuedVariable], values: [&str; 3]) -> u32 {
+    MultiValuedVariable::term(variables, &values).unwrap()
+}
+
+fn assert_equivalent(
+    solution: &MultiValuedSolution,
+    variables: &[MultiValuedVariable],
+    minterms: &[u32],
+    dont_cares: &[u32],
+) {
+    let term_count = variables
+        .iter()
+        .map(|variable| variable.values.len() as u32)
+        .product::<u32>();
+
+    for term in (0..term_count).filter(|term| !dont_cares.contains(term)) {
+        let mut values = vec![];
+        let mut rest = term;
+
+        for variable in variables.iter().rev() {
+            values.push(&variable.values[(rest % variable.values.len() as u32) as usize]);
+            rest /= variable.values.len() as u32;
+        }
+
+        values.reverse();
+
+        let value = match solution {
+            MultiValuedSolution::One => true,
+            MultiValuedSolution::Zero => false,
+            MultiValuedSolution::SOP(products) => products.iter().any(|product| {
+                product.iter().all(|literal| {
+                    let i = variables
+                        .iter()
+                        .position(|variable| variable.name == literal.name)
+                        .unwrap();
+
+                    literal.values.contains(values[i])
+                })
+            }),
+        };
+
+        assert_eq!(value, minterms.contains(&term), "{} at {}", solution, term);
+    }
+}
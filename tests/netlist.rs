@@ -0,0 +1,285 @@
+use quine_mccluskey as qmc;
+use quine_mccluskey::{Gate, GateKind, Signal};
+
+static VARIABLES: [&str; 3] = ["sel", "a", "b"];
+
+#[test]
+fn inverters() {
+    let (sop, pos) = solutions();
+    let outputs = [("y", &sop), ("z", &pos)];
+
+    let shared = qmc::Netlist::new(&VARIABLES, &outputs, qmc::Inverters::Shared).unwrap();
+
+    assert_eq!(
+        shared.gates,
+        [
+            Gate::new(GateKind::And, [Signal::Input(0), Signal::Input(2)]),
+            Gate::new(GateKind::Not, [Signal::Input(0)]),
+            Gate::new(GateKind::And, [Signal::Gate(1), Signal::Input(1)]),
+            Gate::new(GateKind::Or, [Signal::Gate(0), Signal::Gate(2)]),
+            Gate::new(GateKind::Or, [Signal::Input(0), Signal::Input(1)]),
+            Gate::new(GateKind::Or, [Signal::Gate(1), Signal::Input(2)]),
+            Gate::new(GateKind::And, [Signal::Gate(4), Signal::Gate(5)]),
+        ]
+    );
+    assert_eq!(
+        shared.outputs,
+        [
+            ("y".to_owned(), Signal::Gate(3)),
+            ("z".to_owned(), Signal::Gate(6))
+        ]
+    );
+
+    let per_literal = qmc::Netlist::new(&VARIABLES, &outputs, qmc::Inverters::PerLiteral).unwrap();
+
+    assert_eq!(
+        per_literal
+            .gates
+            .iter()
+            .filter(|gate| gate.kind == GateKind::Not)
+            .count(),
+        2
+    );
+    assert_eq!(
+        per_literal.gates[5],
+        Gate::new(GateKind::Not, [Signal::Input(0)])
+    );
+}
+
+#[test]
+fn single_terms_and_constants() {
+    let solution = qmc::minimize(&VARIABLES[..2], &[2], &[0, 1, 3], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let literal = qmc::minimize(&VARIABLES[..2], &[0, 1], &[2, 3], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let netlist = qmc::Netlist::new(
+        &VARIABLES[..2],
+        &[
+            ("y", &solution),
+            ("z", &literal),
+            ("one", &qmc::Solution::One),
+            ("zero", &qmc::Solution::Zero),
+        ],
+        qmc::Inverters::Shared,
+    )
+    .unwrap();
+
+    assert_eq!(
+        netlist.gates,
+        [
+            Gate::new(GateKind::Not, [Signal::Input(1)]),
+            Gate::new(GateKind::And, [Signal::Input(0), Signal::Gate(0)]),
+            Gate::new(GateKind::Not, [Signal::Input(0)]),
+        ]
+    );
+    assert_eq!(
+        netlist.outputs,
+        [
+            ("y".to_owned(), Signal::Gate(1)),
+            ("z".to_owned(), Signal::Gate(2)),
+            ("one".to_owned(), Signal::One),
+            ("zero".to_owned(), Signal::Zero),
+        ]
+    );
+}
+
+#[test]
+fn dot() {
+    let (sop, pos) = solutions();
+    let netlist = qmc::Netlist::new(
+        &VARIABLES,
+        &[("y", &sop), ("z", &pos), ("\"one\"", &qmc::Solution::One)],
+        qmc::Inverters::Shared,
+    )
+    .unwrap();
+
+    assert_eq!(
+        netlist.to_dot("mux"),
+        r#"digraph "mux" {
+    rankdir=LR;
+
+    in0 [label="sel", shape=none];
+    in1 [label="a", shape=none];
+    in2 [label="b", shape=none];
+    one [label="1", shape=none];
+    g0 [label="AND", shape=box];
+    g1 [label="NOT", shape=box];
+    g2 [label="AND", shape=box];
+    g3 [label="OR", shape=box];
+    g4 [label="OR", shape=box];
+    g5 [label="OR", shape=box];
+    g6 [label="AND", shape=box];
+    out0 [label="y", shape=none];
+    out1 [label="z", shape=none];
+    out2 [label="\"one\"", shape=none];
+
+    in0 -> g0;
+    in2 -> g0;
+    in0 -> g1;
+    g1 -> g2;
+    in1 -> g2;
+    g0 -> g3;
+    g2 -> g3;
+    in0 -> g4;
+    in1 -> g4;
+    g1 -> g5;
+    in2 -> g5;
+    g4 -> g6;
+    g5 -> g6;
+    g3 -> out0;
+    g6 -> out1;
+    one -> out2;
+}
+"#
+    );
+}
+
+#[test]
+fn universal_gates() {
+    let (sop, pos) = solutions();
+
+    let sop_netlist =
+        qmc::Netlist::new(&VARIABLES, &[("y", &sop)], qmc::Inverters::Shared).unwrap();
+    let nand = sop_netlist.to_nand();
+
+    assert_eq!(
+        nand.gates,
+        [
+            Gate::new(GateKind::Nand, [Signal::Input(0), Signal::Input(2)]),
+            Gate::new(GateKind::Nand, [Signal::Input(0)]),
+            Gate::new(GateKind::Nand, [Signal::Gate(1), Signal::Input(1)]),
+            Gate::new(GateKind::Nand, [Signal::Gate(0), Signal::Gate(2)]),
+        ]
+    );
+    assert_eq!(nand.outputs, [("y".to_owned(), Signal::Gate(3))]);
+    assert_eq!(nand.gate_count(), sop_netlist.gate_count());
+    assert_eq!(nand.depth(), 3);
+
+    let pos_netlist =
+        qmc::Netlist::new(&VARIABLES, &[("z", &pos)], qmc::Inverters::Shared).unwrap();
+    let nor = pos_netlist.to_nor();
+
+    assert_eq!(
+        nor.gates,
+        [
+            Gate::new(GateKind::Nor, [Signal::Input(0), Signal::Input(1)]),
+            Gate::new(GateKind::Nor, [Signal::Input(0)]),
+            Gate::new(GateKind::Nor, [Signal::Gate(1), Signal::Input(2)]),
+            Gate::new(GateKind::Nor, [Signal::Gate(0), Signal::Gate(2)]),
+        ]
+    );
+    assert_eq!(nor.gate_count_of(GateKind::Nor), 4);
+    assert_eq!(nor.depth(), 3);
+
+    let mixed = sop_netlist.to_nor();
+
+    assert_eq!(mixed.gate_count_of(GateKind::Nor), mixed.gate_count());
+    assert_eq!(mixed.gate_count(), 7);
+    assert_eq!(mixed.depth(), 4);
+}
+
+#[test]
+fn universal_gates_equivalence() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let functions: [(&[u32], &[u32]); 4] = [
+        (&[0, 2, 5, 7, 8, 10, 13, 15], &[1, 3, 4, 6, 9, 11, 12, 14]),
+        (&[1, 2, 4, 7, 8, 11, 13, 14], &[0, 3, 5, 6, 9, 10, 12, 15]),
+        (&[0, 1, 2, 3, 15], &[4, 5, 6, 7, 8, 9]),
+        (&[6], &[0, 1, 2, 3, 4, 5, 7, 8, 9, 10, 11, 12, 13, 14, 15]),
+    ];
+
+    for (minterms, maxterms) in functions {
+        for form in [qmc::SOP, qmc::POS] {
+            let solution = qmc::minimize(variables, minterms, maxterms, form, false, None)
+                .unwrap()
+                .pop()
+                .unwrap();
+
+            for inverters in [qmc::Inverters::Shared, qmc::Inverters::PerLiteral] {
+                let netlist = qmc::Netlist::new(
+                    variables,
+                    &[("y", &solution), ("one", &qmc::Solution::One)],
+                    inverters,
+                )
+                .unwrap();
+
+                for converted in [
+                    netlist.to_nand(),
+                    netlist.to_nor(),
+                    netlist.to_nand().to_nor(),
+                ] {
+                    for &term in minterms {
+                        assert_eq!(simulate(&converted, term, 4), [true, true]);
+                    }
+
+                    for &term in maxterms {
+                        assert_eq!(simulate(&converted, term, 4), [false, true]);
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "DuplicateVariables")]
+fn duplicate_names() {
+    qmc::Netlist::new(
+        &VARIABLES,
+        &[("y", &qmc::Solution::One), ("y", &qmc::Solution::Zero)],
+        qmc::Inverters::Shared,
+    )
+    .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "UnknownVariable")]
+fn unknown_variable() {
+    let (sop, _) = solutions();
+
+    qmc::Netlist::new(&VARIABLES[..2], &[("y", &sop)], qmc::Inverters::Shared).unwrap();
+}
+
+fn solutions() -> (qmc::Solution, qmc::Solution) {
+    let solve = |form| {
+        qmc::minimize(&VARIABLES, &[2, 3, 5, 7], &[0, 1, 4, 6], form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    (solve(qmc::SOP), solve(qmc::POS))
+}
+
+fn simulate(netlist: &qmc::Netlist, term: u32, variable_count: usize) -> Vec<bool> {
+    let mut values: Vec<bool> = vec![];
+    let value = |values: &[bool], signal: &Signal| match *signal {
+        Signal::Input(i) => term >> (variable_count - i - 1) & 1 == 1,
+        Signal::Gate(i) => values[i],
+        Signal::Zero => false,
+        Signal::One => true,
+    };
+
+    for gate in &netlist.gates {
+        let mut inputs = gate.inputs.iter().map(|signal| value(&values, signal));
+
+        values.push(match gate.kind {
+            GateKind::Not => !inputs.next().unwrap(),
+            GateKind::And => inputs.all(|input| input),
+            GateKind::Or => inputs.any(|input| input),
+            GateKind::Nand => !inputs.all(|input| input),
+            GateKind::Nor => !inputs.any(|input| input),
+        });
+    }
+
+    netlist
+        .outputs
+        .iter()
+        .map(|(_, signal)| value(&values, signal))
+        .collect()
+}
@@ -0,0 +1,113 @@
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use quine_mccluskey as qmc;
+use quine_mccluskey::Progress;
+
+#[test]
+fn cyclic() {
+    let (steps, mut minimizer) = record(
+        qmc::Minimizer::new(&["A", "B", "C"])
+            .minterms(&[0, 1, 2, 5, 6, 7])
+            .maxterms(&[3, 4]),
+    );
+
+    minimizer.solve().unwrap();
+
+    assert_eq!(
+        *steps.lock().unwrap(),
+        [
+            Progress::Grouping { terms: 6 },
+            Progress::Combining {
+                round: 1,
+                implicants: 6,
+                prime_implicants: 0,
+            },
+            Progress::Combining {
+                round: 2,
+                implicants: 6,
+                prime_implicants: 0,
+            },
+            Progress::ChartReduction {
+                implicants: 6,
+                terms: 6,
+                essential_prime_implicants: 0,
+            },
+            Progress::PetrickDistribution {
+                sums: 6,
+                products: 12,
+            },
+            Progress::PetrickDistribution {
+                sums: 3,
+                products: 8,
+            },
+            Progress::PetrickDistribution {
+                sums: 2,
+                products: 6,
+            },
+        ]
+    );
+
+    // Solving again after an edit reuses the prime implicants.
+    steps.lock().unwrap().clear();
+    minimizer.add_maxterm(0).unwrap();
+    minimizer.solve().unwrap();
+
+    assert_eq!(
+        steps.lock().unwrap()[0],
+        Progress::ChartReduction {
+            implicants: 4,
+            terms: 5,
+            essential_prime_implicants: 0,
+        }
+    );
+}
+
+#[test]
+fn timeout() {
+    let n = 10;
+    let kinds = (0..1 << n)
+        .map(|term: u32| (term.wrapping_mul(2654435761) >> 13) % 3)
+        .collect::<Vec<_>>();
+    let terms = |kind| {
+        (0..1 << n)
+            .filter(|&term| kinds[term as usize] == kind)
+            .collect::<Vec<_>>()
+    };
+
+    let (steps, mut minimizer) = record(
+        qmc::Minimizer::new(&qmc::DEFAULT_VARIABLES[..n])
+            .minterms(&terms(0))
+            .maxterms(&terms(1))
+            .timeout(Duration::from_millis(500)),
+    );
+
+    assert!(matches!(minimizer.solve(), Err(qmc::Error::Timeout)));
+
+    let steps = steps.lock().unwrap();
+    let rounds = steps
+        .iter()
+        .filter_map(|step| match step {
+            Progress::Combining { round, .. } => Some(*round),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+
+    assert_eq!(
+        steps[0],
+        Progress::Grouping {
+            terms: (1 << n) - terms(1).len()
+        }
+    );
+    assert_eq!(rounds, (1..=rounds.len()).collect::<Vec<_>>());
+}
+
+fn record(minimizer: qmc::Minimizer) -> (Arc<Mutex<Vec<Progress>>>, qmc::Minimizer) {
+    let steps = Arc::new(Mutex::new(vec![]));
+    let steps_clone = steps.clone();
+
+    (
+        steps,
+        minimizer.progress(move |progress| steps_clone.lock().unwrap().push(progress.clone())),
+    )
+}
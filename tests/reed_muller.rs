@@ -0,0 +1,89 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn anf() {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+
+    assert_eq!(qmc::anf(variables, &[]).unwrap().to_string(), "0");
+    assert_eq!(
+        qmc::anf(variables, &[0, 1, 2, 3, 4, 5, 6, 7])
+            .unwrap()
+            .to_string(),
+        "1"
+    );
+    assert_eq!(
+        qmc::anf(variables, &[1, 2, 4, 7]).unwrap().to_string(),
+        "A ⊕ B ⊕ C"
+    );
+    assert_eq!(qmc::anf(variables, &[0]).unwrap().product_count(), 8);
+}
+
+#[test]
+fn fixed_polarity() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+
+    for seed in 0..10u32 {
+        let minterms = terms(4, seed);
+
+        for polarity in 0..16 {
+            let expansion = qmc::reed_muller(variables, &minterms, polarity).unwrap();
+
+            for variable in expansion.products().iter().flatten() {
+                let position = variables
+                    .iter()
+                    .position(|&name| name == variable.name)
+                    .unwrap();
+
+                assert_eq!(variable.is_negated, polarity >> (3 - position) & 1 == 1);
+            }
+
+            for term in 0..16 {
+                assert_eq!(expansion.evaluate(term), minterms.contains(&term));
+            }
+        }
+    }
+}
+
+#[test]
+fn best_polarity() {
+    let variables = &qmc::DEFAULT_VARIABLES[..5];
+
+    for seed in 0..10u32 {
+        let minterms = terms(5, seed);
+        let (best, polarity) = qmc::minimize_reed_muller(variables, &minterms).unwrap();
+        let cost = |expansion: &qmc::Esop| (expansion.product_count(), expansion.literal_count());
+
+        assert_eq!(
+            cost(&best),
+            cost(&qmc::reed_muller(variables, &minterms, polarity).unwrap())
+        );
+        assert_eq!(
+            cost(&best),
+            (0..32)
+                .map(|polarity| cost(&qmc::reed_muller(variables, &minterms, polarity).unwrap()))
+                .min()
+                .unwrap()
+        );
+
+        for term in 0..32 {
+            assert_eq!(best.evaluate(term), minterms.contains(&term));
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "TermOutOfBounds")]
+fn out_of_bounds() {
+    qmc::anf(&qmc::DEFAULT_VARIABLES[..2], &[4]).unwrap();
+}
+
+fn terms(variable_count: usize, seed: u32) -> Vec<u32> {
+    (0..1 << variable_count)
+        .filter(|term: &u32| {
+            term.wrapping_mul(2654435761_u32.wrapping_add(seed * 97))
+                .rotate_left(seed)
+                % 3
+                == 0
+        })
+        .collect()
+}
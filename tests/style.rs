@@ -0,0 +1,107 @@
+use quine_mccluskey as qmc;
+
+#[test]
+fn presets() {
+    test(
+        qmc::Style::unicode(),
+        "B ∨ (A ∧ ~C) ∨ (~A ∧ C)",
+        "(A ∨ B ∨ C) ∧ (~A ∨ B ∨ ~C)",
+    );
+    test(
+        qmc::Style::ascii(),
+        "B | (A & !C) | (!A & C)",
+        "(A | B | C) & (!A | B | !C)",
+    );
+    test(
+        qmc::Style::c(),
+        "B || (A && !C) || (!A && C)",
+        "(A || B || C) && (!A || B || !C)",
+    );
+    test(
+        qmc::Style::python(),
+        "B or A and not C or not A and C",
+        "(A or B or C) and (not A or B or not C)",
+    );
+    test(
+        qmc::Style::textbook(),
+        "B + AC' + A'C",
+        "(A + B + C)(A' + B + C')",
+    );
+    test(
+        qmc::Style::overline(),
+        "B + AC\u{305} + A\u{305}C",
+        "(A + B + C)(A\u{305} + B + C\u{305})",
+    );
+}
+
+#[test]
+fn options() {
+    test(
+        qmc::Style {
+            spaced: false,
+            ..qmc::Style::unicode()
+        },
+        "B∨(A∧~C)∨(~A∧C)",
+        "(A∨B∨C)∧(~A∨B∨~C)",
+    );
+    test(
+        qmc::Style {
+            parentheses: qmc::Parentheses::Precedence,
+            ..qmc::Style::unicode()
+        },
+        "B ∨ A ∧ ~C ∨ ~A ∧ C",
+        "(A ∨ B ∨ C) ∧ (~A ∨ B ∨ ~C)",
+    );
+
+    let solution = qmc::minimize(&["A", "B"], &[3], &[0, 1, 2], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(solution.format(&qmc::Style::unicode()), "A ∧ B");
+    assert_eq!(
+        solution.format(&qmc::Style {
+            parentheses: qmc::Parentheses::Always,
+            ..qmc::Style::unicode()
+        }),
+        "(A ∧ B)"
+    );
+}
+
+#[test]
+fn constants() {
+    assert_eq!(qmc::Solution::One.format(&qmc::Style::python()), "True");
+    assert_eq!(qmc::Solution::Zero.format(&qmc::Style::python()), "False");
+    assert_eq!(qmc::Solution::One.format(&qmc::Style::c()), "1");
+    assert_eq!(qmc::Solution::Zero.format(&qmc::Style::c()), "0");
+}
+
+#[test]
+fn overlined_names() {
+    let solution = qmc::minimize(&["Ab", "Cd"], &[0], &[1, 2, 3], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        solution.format(&qmc::Style::overline()),
+        "A\u{305}b\u{305}C\u{305}d\u{305}"
+    );
+}
+
+fn test(style: qmc::Style, expected_sop: &str, expected_pos: &str) {
+    let variables = &qmc::DEFAULT_VARIABLES[..3];
+    let minterms = &[4, 6, 7, 1, 2, 3];
+    let maxterms = &[5, 0];
+
+    for (form, expected) in [(qmc::SOP, expected_sop), (qmc::POS, expected_pos)] {
+        assert_eq!(
+            qmc::minimize(variables, minterms, maxterms, form, false, None)
+                .unwrap()
+                .pop()
+                .unwrap()
+                .format(&style),
+            expected
+        );
+    }
+}
@@ -1,4 +1,6 @@
-use std::time::Duration;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use quine_mccluskey as qmc;
 
@@ -84,3 +86,101 @@ fn timeout3() {
     )
     .unwrap();
 }
+
+#[test]
+fn timeout_error() {
+    let start = Instant::now();
+
+    assert!(matches!(
+        qmc::minimize(
+            &qmc::DEFAULT_VARIABLES[..10],
+            TERMS1,
+            TERMS2,
+            qmc::SOP,
+            false,
+            Some(Duration::from_millis(200)),
+        ),
+        Err(qmc::Error::Timeout)
+    ));
+    assert!(start.elapsed() < Duration::from_secs(10));
+}
+
+#[test]
+fn timeout_on_calling_thread() {
+    let threads = Arc::new(Mutex::new(vec![]));
+    let threads_clone = threads.clone();
+
+    let result = qmc::Minimizer::new(&qmc::DEFAULT_VARIABLES[..10])
+        .minterms(TERMS1)
+        .maxterms(TERMS2)
+        .timeout(Duration::from_millis(200))
+        .progress(move |_| threads_clone.lock().unwrap().push(thread::current().id()))
+        .solve();
+
+    assert!(matches!(result, Err(qmc::Error::Timeout)));
+
+    let threads = threads.lock().unwrap();
+
+    assert!(!threads.is_empty());
+    assert!(threads.iter().all(|&id| id == thread::current().id()));
+}
+
+#[test]
+fn long_timeout() {
+    let solutions = qmc::minimize(
+        &["A", "B"],
+        &[1, 3],
+        &[0, 2],
+        qmc::SOP,
+        false,
+        Some(Duration::MAX),
+    )
+    .unwrap();
+
+    assert_eq!(solutions[0].to_string(), "B");
+}
+
+#[test]
+fn timeout_thread() {
+    let start = Instant::now();
+
+    let result = qmc::Minimizer::new(&qmc::DEFAULT_VARIABLES[..10])
+        .minterms(TERMS1)
+        .maxterms(TERMS2)
+        .timeout(Duration::from_millis(200))
+        .timeout_thread(true)
+        .solve();
+
+    assert!(matches!(result, Err(qmc::Error::Timeout)));
+    assert!(start.elapsed() < Duration::from_secs(10));
+
+    // Finishing early wakes the thread instead of waiting for the timeout.
+    let start = Instant::now();
+
+    let solutions = qmc::Minimizer::new(&["A", "B"])
+        .minterms(&[1, 3])
+        .maxterms(&[0, 2])
+        .timeout(Duration::from_secs(10))
+        .timeout_thread(true)
+        .solve()
+        .unwrap();
+
+    assert_eq!(solutions[0].to_string(), "B");
+    assert!(start.elapsed() < Duration::from_secs(5));
+}
+
+#[test]
+fn timeout_thread_with_cancellation() {
+    let token = qmc::CancellationToken::new();
+    token.cancel();
+
+    let result = qmc::Minimizer::new(&qmc::DEFAULT_VARIABLES[..10])
+        .minterms(TERMS1)
+        .maxterms(TERMS2)
+        .timeout(Duration::from_secs(10))
+        .timeout_thread(true)
+        .cancellation_token(&token)
+        .solve();
+
+    assert!(matches!(result, Err(qmc::Error::Cancelled)));
+}
@@ -0,0 +1,132 @@
+use quine_mccluskey as qmc;
+use quine_mccluskey::{Counterexample, Solution, Verification};
+
+#[test]
+fn equivalent() {
+    let variables = &qmc::DEFAULT_VARIABLES[..4];
+    let minterms = [0, 2, 5, 6, 7, 8, 10, 13, 15];
+    let maxterms = (0..16)
+        .filter(|term| !minterms.contains(term))
+        .collect::<Vec<_>>();
+    let sop = minimize(variables, &minterms, &maxterms, qmc::SOP);
+    let pos = minimize(variables, &minterms, &maxterms, qmc::POS);
+
+    assert!(sop.is_equivalent(&pos).is_equivalent());
+    assert!(pos.is_equivalent(&sop).is_equivalent());
+    assert_eq!(
+        sop.verify_against(variables, &minterms, &maxterms).unwrap(),
+        Verification::Equivalent
+    );
+    assert!(Solution::One.is_equivalent(&Solution::One).is_equivalent());
+}
+
+#[test]
+fn hand_written() {
+    // (a ∧ b) ∨ (~a ∧ c) ∨ (b ∧ c), whose consensus term is redundant.
+    let hand_written = Solution::SOP(vec![
+        vec![variable("a", false), variable("b", false)],
+        vec![variable("a", true), variable("c", false)],
+        vec![variable("b", false), variable("c", false)],
+    ]);
+    let solution = minimize(&["a", "b", "c"], &[1, 3, 6, 7], &[0, 2, 4, 5], qmc::SOP);
+
+    assert!(solution.is_equivalent(&hand_written).is_equivalent());
+    assert_eq!(solution.literal_count(), 4);
+}
+
+#[test]
+fn counterexample() {
+    let xor = minimize(&["a", "b"], &[1, 2], &[0, 3], qmc::SOP);
+    let or = minimize(&["a", "b"], &[1, 2, 3], &[0], qmc::SOP);
+
+    assert_eq!(
+        xor.is_equivalent(&or),
+        Verification::Counterexample(Counterexample {
+            assignment: vec![("a".to_owned(), true), ("b".to_owned(), true)],
+            value: false,
+        })
+    );
+    assert_eq!(
+        xor.is_equivalent(&Solution::Zero),
+        Verification::Counterexample(Counterexample {
+            assignment: vec![("a".to_owned(), false), ("b".to_owned(), true)],
+            value: true,
+        })
+    );
+
+    // Variables only on one side are part of the assignment.
+    let c = Solution::POS(vec![vec![variable("c", false)]]);
+
+    match Solution::One.is_equivalent(&c) {
+        Verification::Counterexample(counterexample) => {
+            assert_eq!(counterexample.to_string(), "c = 0 gives 1");
+        }
+        Verification::Equivalent => panic!("1 is not c"),
+    }
+}
+
+#[test]
+fn verify_against() {
+    let variables = ["x", "y", "z"];
+    let solution = minimize(&variables, &[3, 5, 6, 7], &[0, 1, 2, 4], qmc::SOP);
+
+    assert!(solution
+        .verify_against(&variables, &[3, 7], &[0, 4])
+        .unwrap()
+        .is_equivalent());
+
+    let result = solution.verify_against(&variables, &[3, 4], &[5]).unwrap();
+
+    assert_eq!(
+        result,
+        Verification::Counterexample(Counterexample {
+            assignment: vec![
+                ("x".to_owned(), true),
+                ("y".to_owned(), false),
+                ("z".to_owned(), false)
+            ],
+            value: false,
+        })
+    );
+    assert_eq!(
+        result,
+        solution.verify_against(&variables, &[4, 3], &[5]).unwrap()
+    );
+}
+
+#[test]
+fn verify_against_errors() {
+    let solution = minimize(&["x", "y"], &[1], &[0, 2, 3], qmc::SOP);
+
+    assert!(matches!(
+        solution.verify_against(&["x"], &[], &[]),
+        Err(qmc::Error::UnknownVariable(name)) if name == "y"
+    ));
+    assert!(matches!(
+        solution.verify_against(&["x", "y"], &[1], &[1]),
+        Err(qmc::Error::TermConflict(_))
+    ));
+    assert!(matches!(
+        solution.verify_against(&["x", "y"], &[4], &[]),
+        Err(qmc::Error::TermOutOfBounds { .. })
+    ));
+}
+
+fn minimize<T: AsRef<str>>(
+    variables: &[T],
+    minterms: &[u32],
+    maxterms: &[u32],
+    form: qmc::Form,
+) -> Solution {
+    qmc::minimize(variables, minterms, maxterms, form, false, None)
+        .unwrap()
+        .pop()
+        .unwrap()
+}
+
+fn variable(name: &str, is_negated: bool) -> qmc::Variable {
+    qmc::Variable {
+        name: name.to_owned(),
+        is_negated,
+    }
+}
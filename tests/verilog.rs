@@ -0,0 +1,202 @@
+use quine_mccluskey as qmc;
+
+static VARIABLES: [&str; 3] = ["sel", "a", "b"];
+
+#[test]
+fn assign() {
+    let (sop, pos) = solutions();
+
+    assert_eq!(
+        qmc::Verilog::default()
+            .module(
+                "mux",
+                &VARIABLES,
+                &[("y", &sop), ("z", &pos), ("one", &qmc::Solution::One)],
+            )
+            .unwrap(),
+        "module mux (
+    input wire sel,
+    input wire a,
+    input wire b,
+    output wire y,
+    output wire z,
+    output wire one
+);
+
+    assign y = (sel & b) | (~sel & a);
+    assign z = (sel | a) & (~sel | b);
+    assign one = 1'b1;
+
+endmodule
+"
+    );
+}
+
+#[test]
+fn gate_level() {
+    let (sop, pos) = solutions();
+    let verilog = qmc::Verilog {
+        gate_level: true,
+        ..Default::default()
+    };
+
+    assert_eq!(
+        verilog
+            .module(
+                "mux",
+                &VARIABLES,
+                &[("y", &sop), ("z", &pos), ("one", &qmc::Solution::One)],
+            )
+            .unwrap(),
+        "module mux (
+    input wire sel,
+    input wire a,
+    input wire b,
+    output wire y,
+    output wire z,
+    output wire one
+);
+
+    wire sel_n;
+    wire y_0;
+    wire y_1;
+    wire z_0;
+    wire z_1;
+
+    not (sel_n, sel);
+    and (y_0, sel, b);
+    and (y_1, sel_n, a);
+    or (y, y_0, y_1);
+    or (z_0, sel, a);
+    or (z_1, sel_n, b);
+    and (z, z_0, z_1);
+    assign one = 1'b1;
+
+endmodule
+"
+    );
+
+    let solution = qmc::minimize(&VARIABLES[..2], &[2], &[0, 1, 3], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    assert_eq!(
+        verilog
+            .module("f", &VARIABLES[..2], &[("y", &solution)])
+            .unwrap(),
+        "module f (
+    input wire sel,
+    input wire a,
+    output wire y
+);
+
+    wire a_n;
+
+    not (a_n, a);
+    and (y, sel, a_n);
+
+endmodule
+"
+    );
+}
+
+#[test]
+fn identifiers() {
+    let variables = ["in", "a+b", "2x", "in_n"];
+    let solution = qmc::minimize(&variables, &[0, 15], &[1, 2, 4, 8], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let sanitized = qmc::Verilog {
+        gate_level: true,
+        ..Default::default()
+    }
+    .module("module", &variables, &[("out", &solution)])
+    .unwrap();
+
+    assert!(sanitized.starts_with(
+        "module module_ (
+    input wire in,
+    input wire a_b,
+    input wire _2x,
+    input wire in_n,
+    output wire out
+);"
+    ));
+    assert!(sanitized.contains("not (in_n_1, in);"));
+
+    let escaped = qmc::Verilog {
+        escape_identifiers: true,
+        ..Default::default()
+    }
+    .module("module", &variables, &[("out", &solution)])
+    .unwrap();
+
+    assert!(escaped.starts_with(
+        "module \\module  (
+    input wire in,
+    input wire \\a+b ,
+    input wire \\2x ,
+    input wire in_n,
+    output wire out
+);"
+    ));
+}
+
+#[test]
+fn escaped_identifiers() {
+    let variables = ["a_b", "a b", "a\tb", "été+1"];
+    let solution = qmc::minimize(&variables, &[15], &[0], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    let escaped = qmc::Verilog {
+        escape_identifiers: true,
+        ..Default::default()
+    }
+    .module("f", &variables, &[("out", &solution)])
+    .unwrap();
+
+    assert!(escaped.starts_with(
+        "module f (
+    input wire a_b,
+    input wire \\a_b_1 ,
+    input wire \\a_b_2 ,
+    input wire \\_t_+1 ,
+    output wire out
+);"
+    ));
+    assert!(escaped.is_ascii());
+}
+
+#[test]
+#[should_panic(expected = "DuplicateVariables")]
+fn duplicate_ports() {
+    qmc::Verilog::default()
+        .module("f", &VARIABLES, &[("a", &qmc::Solution::One)])
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "UnknownVariable")]
+fn unknown_variable() {
+    let (sop, _) = solutions();
+
+    qmc::Verilog::default()
+        .module("f", &VARIABLES[..2], &[("y", &sop)])
+        .unwrap();
+}
+
+fn solutions() -> (qmc::Solution, qmc::Solution) {
+    let solve = |form| {
+        qmc::minimize(&VARIABLES, &[2, 3, 5, 7], &[0, 1, 4, 6], form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    (solve(qmc::SOP), solve(qmc::POS))
+}
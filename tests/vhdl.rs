@@ -0,0 +1,150 @@
+use quine_mccluskey as qmc;
+
+static VARIABLES: [&str; 3] = ["sel", "a", "b"];
+
+#[test]
+fn entity() {
+    let solve = |form| {
+        qmc::minimize(&VARIABLES, &[2, 3, 5, 7], &[0, 1, 4, 6], form, false, None)
+            .unwrap()
+            .pop()
+            .unwrap()
+    };
+
+    assert_eq!(
+        qmc::Vhdl::default()
+            .entity(
+                "mux",
+                &VARIABLES,
+                &[
+                    ("y", &solve(qmc::SOP)),
+                    ("z", &solve(qmc::POS)),
+                    ("zero", &qmc::Solution::Zero),
+                ],
+            )
+            .unwrap(),
+        "library ieee;
+use ieee.std_logic_1164.all;
+
+entity mux is
+    port (
+        sel : in std_logic;
+        a : in std_logic;
+        b : in std_logic;
+        y : out std_logic;
+        z : out std_logic;
+        zero : out std_logic
+    );
+end entity mux;
+
+architecture rtl of mux is
+begin
+    y <= (sel and b) or (not sel and a);
+    z <= (sel or a) and (not sel or b);
+    zero <= '0';
+end architecture rtl;
+"
+    );
+}
+
+#[test]
+fn testbench() {
+    assert_eq!(
+        qmc::Vhdl::default()
+            .testbench("mux", &VARIABLES, &[("y", &[2, 3, 5, 7], &[0, 1, 4])])
+            .unwrap(),
+        "library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity mux_tb is
+end entity mux_tb;
+
+architecture test of mux_tb is
+    signal sel : std_logic;
+    signal a : std_logic;
+    signal b : std_logic;
+    signal y : std_logic;
+    constant y_expected : std_logic_vector(0 to 7) := \"001101-1\";
+begin
+    uut : entity work.mux
+        port map (
+            sel => sel,
+            a => a,
+            b => b,
+            y => y
+        );
+
+    stimulus : process
+        variable term : std_logic_vector(2 downto 0);
+    begin
+        for i in 0 to 7 loop
+            term := std_logic_vector(to_unsigned(i, 3));
+            sel <= term(2);
+            a <= term(1);
+            b <= term(0);
+            wait for 1 ns;
+            assert y_expected(i) = '-' or y = y_expected(i)
+                report \"y is wrong for term \" & integer'image(i) severity error;
+        end loop;
+        wait;
+    end process stimulus;
+end architecture test;
+"
+    );
+}
+
+#[test]
+fn identifiers() {
+    let variables = ["In", "a+b", "2x", "I", "_term_"];
+    let solution = qmc::minimize(&variables, &[0], &[1], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+    let vhdl = qmc::Vhdl::default();
+
+    let entity = vhdl
+        .entity("my entity", &variables, &[("i", &solution)])
+        .unwrap();
+
+    assert!(entity.contains(
+        "entity my_entity is
+    port (
+        x_In : in std_logic;
+        a_b : in std_logic;
+        x_2x : in std_logic;
+        I : in std_logic;
+        term : in std_logic;
+        i_1 : out std_logic
+    );"
+    ));
+
+    let testbench = vhdl
+        .testbench("my entity", &variables, &[("i", &[0], &[1])])
+        .unwrap();
+
+    assert!(testbench.contains("entity work.my_entity"));
+    assert!(testbench.contains("for i_2 in 0 to 31 loop"));
+    assert!(testbench.contains("term_1 := std_logic_vector(to_unsigned(i_2, 5));"));
+}
+
+#[test]
+#[should_panic(expected = "TermConflict")]
+fn testbench_term_conflict() {
+    qmc::Vhdl::default()
+        .testbench("mux", &VARIABLES, &[("y", &[1, 2], &[2])])
+        .unwrap();
+}
+
+#[test]
+#[should_panic(expected = "UnknownVariable")]
+fn unknown_variable() {
+    let solution = qmc::minimize(&VARIABLES, &[1], &[0], qmc::SOP, false, None)
+        .unwrap()
+        .pop()
+        .unwrap();
+
+    qmc::Vhdl::default()
+        .entity("f", &VARIABLES[..2], &[("y", &solution)])
+        .unwrap();
+}